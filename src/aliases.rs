@@ -0,0 +1,211 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+
+use crate::types::IngredientList;
+
+/// Suffixes stripped to fold German plurals onto their singular.
+const PLURAL_SUFFIXES: &[&str] = &["en", "er", "n", "e", "s"];
+
+/// A variant spelling of a canonical ingredient.
+#[derive(Debug, Clone, PartialEq)]
+struct Alias {
+    /// Canonical ingredient name.
+    canonical: String,
+    /// Unit hidden in the variant name, e.g. "Zehe" in "Knoblauchzehen".
+    unit: Option<String>,
+}
+
+/// User maintained dictionary mapping variant ingredient names to canonical ones.
+///
+/// One canonical ingredient per line, followed by its variants:
+///
+/// ```text
+/// Zwiebel: Zwiebeln, rote Zwiebel
+/// Knoblauch: Knoblauchzehe (Zehe), Knoblauchzehen (Zehe)
+/// ```
+///
+/// A unit in parentheses is used for ingredients that are listed without a
+/// measure. Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub(crate) struct Aliases {
+    variants: HashMap<String, Alias>,
+    canonical: HashSet<String>,
+}
+
+impl Aliases {
+    /// Load the dictionary, a missing file yields an empty dictionary.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read alias file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut aliases = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim().trim_start_matches("- ");
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((canonical, variants)) = line.split_once(':') else {
+                continue;
+            };
+            let canonical = canonical.trim().to_string();
+
+            for variant in variants.split(',').map(str::trim) {
+                if variant.is_empty() {
+                    continue;
+                }
+                let (name, unit) = match variant.strip_suffix(')').and_then(|v| v.split_once('(')) {
+                    Some((name, unit)) => (name.trim(), Some(unit.trim().to_string())),
+                    None => (variant, None),
+                };
+                aliases.variants.insert(
+                    name.to_string(),
+                    Alias {
+                        canonical: canonical.clone(),
+                        unit,
+                    },
+                );
+            }
+            aliases.canonical.insert(canonical);
+        }
+
+        aliases
+    }
+
+    /// Canonical name of a single ingredient.
+    ///
+    /// Plurals are only folded onto canonical singulars here.
+    pub(crate) fn canonical(&self, name: &str) -> String {
+        if let Some(alias) = self.variants.get(name) {
+            return alias.canonical.clone();
+        }
+        singular(name, &self.canonical).unwrap_or_else(|| name.to_string())
+    }
+
+    /// Rename all ingredients of the list to their canonical name.
+    ///
+    /// Explicit aliases are applied first. Afterwards plurals are folded onto
+    /// a singular that is either canonical or also part of the list.
+    pub(crate) fn apply(&self, list: &mut IngredientList) {
+        for ingredient in list.0.iter_mut() {
+            if let Some(alias) = self.variants.get(&ingredient.name) {
+                ingredient.name = alias.canonical.clone();
+                if ingredient.measure.is_empty() {
+                    if let Some(unit) = &alias.unit {
+                        ingredient.measure = unit.clone();
+                    }
+                }
+            }
+        }
+
+        let known: HashSet<String> = list
+            .0
+            .iter()
+            .map(|i| i.name.clone())
+            .chain(self.canonical.iter().cloned())
+            .collect();
+
+        for ingredient in list.0.iter_mut() {
+            if let Some(singular) = singular(&ingredient.name, &known) {
+                ingredient.name = singular;
+            }
+        }
+    }
+}
+
+/// Find a known singular for a plural name.
+fn singular(name: &str, known: &HashSet<String>) -> Option<String> {
+    PLURAL_SUFFIXES
+        .iter()
+        .filter_map(|suffix| name.strip_suffix(suffix))
+        .find(|stem| !stem.is_empty() && known.contains(*stem))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Ingredient;
+
+    fn make_ingredient(amount: f32, measure: &str, name: &str, dish: &str) -> Ingredient {
+        Ingredient {
+            amount,
+            measure: measure.to_string(),
+            name: name.to_string(),
+            dish: dish.to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_variants_with_units() {
+        let aliases = Aliases::parse(
+            "# Gemüse\nZwiebel: Zwiebeln, rote Zwiebel\n- Knoblauch: Knoblauchzehen (Zehe), Zehe Knoblauch (Zehe)\n",
+        );
+
+        assert_eq!(aliases.variants.len(), 4);
+        assert_eq!(
+            aliases.variants["Zehe Knoblauch"],
+            Alias {
+                canonical: "Knoblauch".to_string(),
+                unit: Some("Zehe".to_string()),
+            }
+        );
+        assert_eq!(aliases.variants["Zwiebeln"].unit, None);
+    }
+
+    #[test]
+    fn test_apply_merges_variants() {
+        let aliases = Aliases::parse("Knoblauch: Knoblauchzehen (Zehe), Zehe Knoblauch (Zehe)\n");
+        let mut list = IngredientList::from(vec![
+            make_ingredient(2.0, "", "Zehe Knoblauch", "Chili"),
+            make_ingredient(3.0, "", "Knoblauchzehen", "Gulasch"),
+            make_ingredient(1.0, "", "Zwiebel", "Chili"),
+            make_ingredient(4.0, "", "Zwiebeln", "Gulasch"),
+            make_ingredient(100.0, "g", "Zucker", "Kuchen"),
+        ]);
+
+        aliases.apply(&mut list);
+        list.accumulate();
+
+        assert_eq!(list.0.len(), 3);
+
+        let garlic = list.0.iter().find(|i| i.name == "Knoblauch").unwrap();
+        assert_eq!(garlic.amount, 5.0);
+        assert_eq!(garlic.measure, "Zehe");
+        assert!(garlic.dish.contains("Chili"));
+        assert!(garlic.dish.contains("Gulasch"));
+
+        let onions = list.0.iter().find(|i| i.name == "Zwiebel").unwrap();
+        assert_eq!(onions.amount, 5.0);
+
+        // No singular "Zuck" exists, the name is kept.
+        assert!(list.0.iter().any(|i| i.name == "Zucker"));
+    }
+
+    #[test]
+    fn test_canonical_name() {
+        let aliases = Aliases::parse(
+            "Ei: Hühnerei
+Knoblauch: Zehe Knoblauch (Zehe)
+",
+        );
+
+        assert_eq!(aliases.canonical("Hühnerei"), "Ei");
+        assert_eq!(aliases.canonical("Eier"), "Ei");
+        assert_eq!(aliases.canonical("Zehe Knoblauch"), "Knoblauch");
+        assert_eq!(aliases.canonical("Mehl"), "Mehl");
+    }
+}
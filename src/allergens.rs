@@ -0,0 +1,413 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fs,
+    path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{aliases::Aliases, types::Ingredient};
+
+/// The 14 allergens that have to be declared in the EU, in the order of the regulation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) enum Allergen {
+    Gluten,
+    Krebstiere,
+    Eier,
+    Fisch,
+    Erdnuesse,
+    Soja,
+    Milch,
+    Schalenfruechte,
+    Sellerie,
+    Senf,
+    Sesam,
+    Sulfite,
+    Lupinen,
+    Weichtiere,
+}
+
+impl Allergen {
+    pub(crate) const ALL: [Allergen; 14] = [
+        Allergen::Gluten,
+        Allergen::Krebstiere,
+        Allergen::Eier,
+        Allergen::Fisch,
+        Allergen::Erdnuesse,
+        Allergen::Soja,
+        Allergen::Milch,
+        Allergen::Schalenfruechte,
+        Allergen::Sellerie,
+        Allergen::Senf,
+        Allergen::Sesam,
+        Allergen::Sulfite,
+        Allergen::Lupinen,
+        Allergen::Weichtiere,
+    ];
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Allergen::Gluten => "Gluten",
+            Allergen::Krebstiere => "Krebstiere",
+            Allergen::Eier => "Eier",
+            Allergen::Fisch => "Fisch",
+            Allergen::Erdnuesse => "Erdnüsse",
+            Allergen::Soja => "Soja",
+            Allergen::Milch => "Milch",
+            Allergen::Schalenfruechte => "Schalenfrüchte",
+            Allergen::Sellerie => "Sellerie",
+            Allergen::Senf => "Senf",
+            Allergen::Sesam => "Sesam",
+            Allergen::Sulfite => "Sulfite",
+            Allergen::Lupinen => "Lupinen",
+            Allergen::Weichtiere => "Weichtiere",
+        }
+    }
+
+    /// Look up an allergen by its name or a common synonym.
+    fn parse(tag: &str) -> Option<Self> {
+        let tag = tag.to_lowercase();
+        let allergen = match tag.as_str() {
+            "gluten" | "weizen" | "roggen" | "gerste" | "hafer" | "dinkel" => Allergen::Gluten,
+            "krebstiere" | "krebstier" => Allergen::Krebstiere,
+            "eier" | "ei" => Allergen::Eier,
+            "fisch" => Allergen::Fisch,
+            "erdnüsse" | "erdnuss" => Allergen::Erdnuesse,
+            "soja" => Allergen::Soja,
+            "milch" | "laktose" => Allergen::Milch,
+            "schalenfrüchte" | "nüsse" | "nuss" => Allergen::Schalenfruechte,
+            "sellerie" => Allergen::Sellerie,
+            "senf" => Allergen::Senf,
+            "sesam" => Allergen::Sesam,
+            "sulfite" | "schwefeldioxid" => Allergen::Sulfite,
+            "lupinen" | "lupine" => Allergen::Lupinen,
+            "weichtiere" | "weichtier" => Allergen::Weichtiere,
+            _ => return None,
+        };
+        Some(allergen)
+    }
+
+    /// Whether the allergen comes from animals killed for it.
+    fn is_meat(self) -> bool {
+        matches!(
+            self,
+            Allergen::Fisch | Allergen::Krebstiere | Allergen::Weichtiere
+        )
+    }
+}
+
+/// Diets a dish can be labelled with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Diet {
+    Vegan,
+    Vegetarisch,
+    Glutenfrei,
+    Laktosefrei,
+}
+
+impl Diet {
+    pub(crate) const ALL: [Diet; 4] = [
+        Diet::Vegan,
+        Diet::Vegetarisch,
+        Diet::Glutenfrei,
+        Diet::Laktosefrei,
+    ];
+
+    /// Look up a diet by its name, e.g. "vegan" or "Vegetarisch".
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        Diet::ALL
+            .into_iter()
+            .find(|diet| diet.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Whether a dish with the given diets is fit for this diet.
+    ///
+    /// Vegan dishes are also vegetarian.
+    pub(crate) fn fits(self, diets: &[Diet]) -> bool {
+        diets.contains(&self) || self == Diet::Vegetarisch && diets.contains(&Diet::Vegan)
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Diet::Vegan => "vegan",
+            Diet::Vegetarisch => "vegetarisch",
+            Diet::Glutenfrei => "glutenfrei",
+            Diet::Laktosefrei => "laktosefrei",
+        }
+    }
+}
+
+/// Table entry of a single ingredient.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Entry {
+    allergens: BTreeSet<Allergen>,
+    /// Meat or other parts of killed animals.
+    meat: bool,
+    /// Other animal products like honey, not vegan.
+    animal: bool,
+    /// Contains milk, but no lactose.
+    lactose_free: bool,
+}
+
+/// Local allergen table keyed by canonical ingredient name.
+///
+/// One ingredient per line with its allergens and flags, `-` marks an
+/// ingredient without any:
+///
+/// ```text
+/// Butter: Milch
+/// Spätzle: Gluten, Eier
+/// Speck: Fleisch
+/// Honig: tierisch
+/// laktosefreie Milch: Milch, laktosefrei
+/// Kartoffel: -
+/// ```
+///
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub(crate) struct AllergenTable {
+    entries: HashMap<String, Entry>,
+}
+
+impl AllergenTable {
+    /// Load the table, returns `None` if the file does not exist.
+    pub(crate) fn from_file(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read allergen file: {}", path.display()))?;
+        Self::parse(&content)
+            .map(Some)
+            .with_context(|| format!("Invalid allergen file: {}", path.display()))
+    }
+
+    /// Parse the table, unknown allergens are an error as they must not get lost.
+    pub(crate) fn parse(content: &str) -> Result<Self> {
+        let mut entries = HashMap::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim().trim_start_matches("- ");
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, tags)) = line.split_once(':') else {
+                continue;
+            };
+            let mut entry = Entry::default();
+            for tag in tags.split(',').map(str::trim) {
+                match tag.to_lowercase().as_str() {
+                    "" | "-" => {}
+                    "fleisch" => entry.meat = true,
+                    "tierisch" => entry.animal = true,
+                    "laktosefrei" => entry.lactose_free = true,
+                    _ => match Allergen::parse(tag) {
+                        Some(allergen) => {
+                            entry.allergens.insert(allergen);
+                        }
+                        None => bail!("line {}: unknown allergen '{tag}' for {}", i + 1, name),
+                    },
+                }
+            }
+            entries.insert(name.trim().to_lowercase(), entry);
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Derive the labels of a dish from its ingredients.
+    pub(crate) fn labels(&self, ingredients: &[Ingredient], aliases: &Aliases) -> Labels {
+        let mut labels = Labels::default();
+        let (mut meat, mut animal, mut lactose) = (false, false, false);
+
+        for ingredient in ingredients {
+            let entry = self
+                .entries
+                .get(&ingredient.name.to_lowercase())
+                .or_else(|| {
+                    self.entries
+                        .get(&aliases.canonical(&ingredient.name).to_lowercase())
+                });
+            let Some(entry) = entry else {
+                if !labels.missing.contains(&ingredient.name) {
+                    labels.missing.push(ingredient.name.clone());
+                }
+                continue;
+            };
+            labels.allergens.extend(&entry.allergens);
+            meat |= entry.meat || entry.allergens.iter().any(|a| a.is_meat());
+            animal |= entry.animal;
+            lactose |= entry.allergens.contains(&Allergen::Milch) && !entry.lactose_free;
+        }
+
+        // Without data for every ingredient no diet can be promised.
+        if labels.missing.is_empty() {
+            let contains = |allergen| labels.allergens.contains(&allergen);
+            let vegetarian = !meat;
+            let vegan =
+                vegetarian && !animal && !contains(Allergen::Eier) && !contains(Allergen::Milch);
+            labels.diets = [
+                (Diet::Vegan, vegan),
+                (Diet::Vegetarisch, vegetarian),
+                (Diet::Glutenfrei, !contains(Allergen::Gluten)),
+                (Diet::Laktosefrei, !lactose),
+            ]
+            .into_iter()
+            .filter_map(|(diet, applies)| applies.then_some(diet))
+            .collect();
+        }
+
+        labels
+    }
+}
+
+/// Allergens and diets of a dish.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Labels {
+    pub(crate) allergens: BTreeSet<Allergen>,
+    /// Diets the dish fits, empty if any ingredient is missing in the table.
+    pub(crate) diets: Vec<Diet>,
+    /// Ingredients without an entry in the allergen table.
+    pub(crate) missing: Vec<String>,
+}
+
+impl Labels {
+    /// Allergen line of a dish, e.g. "**Allergene:** Gluten, Milch (vegetarisch)".
+    pub(crate) fn as_markdown(&self) -> String {
+        let allergens = if self.allergens.is_empty() {
+            "keine".to_string()
+        } else {
+            self.allergens
+                .iter()
+                .map(|allergen| allergen.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut line = format!("**Allergene:** {allergens}");
+        if !self.diets.is_empty() {
+            let diets: Vec<&str> = self.diets.iter().map(|diet| diet.name()).collect();
+            line.push_str(&format!(" ({})", diets.join(", ")));
+        }
+        if !self.missing.is_empty() {
+            line.push_str(&format!(", ohne Angaben für {}", self.missing.join(", ")));
+        }
+        line
+    }
+
+    /// Cells of an allergen matrix row, "x" for contained allergens and
+    /// fitting diets, "?" where the table lacks data.
+    pub(crate) fn cells(&self) -> Vec<&'static str> {
+        let unknown = if self.missing.is_empty() { "" } else { "?" };
+        Allergen::ALL
+            .iter()
+            .map(|allergen| {
+                if self.allergens.contains(allergen) {
+                    "x"
+                } else {
+                    unknown
+                }
+            })
+            .chain(Diet::ALL.iter().map(|diet| {
+                if self.diets.contains(diet) {
+                    "x"
+                } else {
+                    unknown
+                }
+            }))
+            .collect()
+    }
+}
+
+/// Header row and separator of an allergen matrix.
+pub(crate) fn matrix_header() -> String {
+    let columns: Vec<&str> = Allergen::ALL
+        .iter()
+        .map(|allergen| allergen.name())
+        .chain(Diet::ALL.iter().map(|diet| diet.name()))
+        .collect();
+    format!(
+        "| Gericht | {} |\n| --- |{}",
+        columns.join(" | "),
+        " :-: |".repeat(columns.len())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ingredient(name: &str) -> Ingredient {
+        Ingredient {
+            amount: 1.0,
+            measure: String::new(),
+            name: name.to_string(),
+            dish: "Test".to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    const TABLE: &str = "# Allergene\n\
+        Butter: Milch\n\
+        Spätzle: Weizen, Ei\n\
+        Speck: Fleisch\n\
+        Honig: tierisch\n\
+        Laktosefreie Milch: Milch, laktosefrei\n\
+        Kartoffel: -\n\
+        Tofu: Soja\n";
+
+    #[test]
+    fn test_parse_table() {
+        let table = AllergenTable::parse(TABLE).unwrap();
+
+        assert_eq!(table.entries.len(), 7);
+        assert_eq!(
+            table.entries["spätzle"].allergens,
+            BTreeSet::from([Allergen::Gluten, Allergen::Eier])
+        );
+        assert!(table.entries["speck"].meat);
+        assert!(table.entries["kartoffel"].allergens.is_empty());
+
+        let error = AllergenTable::parse("Brot: Glutn\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: unknown allergen 'Glutn' for Brot"
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let table = AllergenTable::parse(TABLE).unwrap();
+        let aliases = Aliases::parse("Kartoffel: Kartoffeln\n");
+        let labels = |names: &[&str]| {
+            table.labels(
+                &names.iter().map(|n| ingredient(n)).collect::<Vec<_>>(),
+                &aliases,
+            )
+        };
+
+        let vegan = labels(&["Kartoffeln", "Tofu"]);
+        assert_eq!(vegan.allergens, BTreeSet::from([Allergen::Soja]));
+        assert_eq!(Diet::ALL.to_vec(), vegan.diets);
+
+        let spaetzle = labels(&["Spätzle", "Laktosefreie Milch"]);
+        assert_eq!(spaetzle.diets, [Diet::Vegetarisch, Diet::Laktosefrei]);
+        assert_eq!(
+            spaetzle.as_markdown(),
+            "**Allergene:** Gluten, Eier, Milch (vegetarisch, laktosefrei)"
+        );
+
+        let honey = labels(&["Kartoffel", "Honig", "Butter"]);
+        assert_eq!(honey.diets, [Diet::Vegetarisch, Diet::Glutenfrei]);
+
+        let unknown = labels(&["Speck", "Pfeffer"]);
+        assert!(unknown.diets.is_empty());
+        assert_eq!(unknown.missing, ["Pfeffer"]);
+        assert_eq!(
+            unknown.as_markdown(),
+            "**Allergene:** keine, ohne Angaben für Pfeffer"
+        );
+        assert_eq!(unknown.cells()[0], "?");
+        assert_eq!(unknown.cells().len(), 18);
+    }
+}
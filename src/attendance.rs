@@ -0,0 +1,277 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
+
+use crate::{diagnostics::Issue, plan::parse_weekday};
+
+/// Arrival or departure of a cohort as written, e.g. "Donnerstag Abendessen".
+#[derive(Debug, Clone, PartialEq)]
+struct Stop {
+    /// Weekday or date.
+    day: String,
+    /// First or last meal eaten, the whole day if not given.
+    meal: Option<String>,
+}
+
+impl Stop {
+    fn parse(text: &str) -> Self {
+        let text = text.trim();
+        match text.split_once(char::is_whitespace) {
+            Some((day, meal)) => Self {
+                day: day.trim_end_matches(',').to_string(),
+                meal: Some(meal.trim().to_string()),
+            },
+            None => Self {
+                day: text.to_string(),
+                meal: None,
+            },
+        }
+    }
+
+    /// Date of the stop, weekdays are the first such day from `from` on.
+    fn date(&self, from: NaiveDate) -> Option<NaiveDate> {
+        if let Some(weekday) = parse_weekday(&self.day) {
+            let ahead =
+                (weekday.num_days_from_monday() + 7 - from.weekday().num_days_from_monday()) % 7;
+            return Some(from + chrono::Days::new(ahead as u64));
+        }
+        NaiveDate::parse_from_str(&self.day, "%Y-%m-%d")
+            .or_else(|_| NaiveDate::parse_from_str(&self.day, "%d.%m.%Y"))
+            .ok()
+            .or_else(|| {
+                let (d, m) = self.day.strip_suffix('.')?.split_once('.')?;
+                NaiveDate::from_ymd_opt(from.year(), m.parse().ok()?, d.parse().ok()?)
+            })
+    }
+}
+
+/// A group of participants that arrives and leaves together.
+#[derive(Debug, Clone, PartialEq)]
+struct Cohort {
+    name: String,
+    people: usize,
+    arrival: Stop,
+    departure: Stop,
+}
+
+/// Who attends the camp when, as named cohorts with arrival and departure.
+///
+/// Read from a markdown table, meals are the rows of a table plan and
+/// arrival and departure meals are both eaten:
+///
+/// ```text
+/// | Gruppe  | Personen | Anreise               | Abreise             |
+/// | Kinder  | 30       | Donnerstag Abendessen | Sonntag Frühstück   |
+/// | Leitung | 8        | Donnerstag            | Sonntag Mittagessen |
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct Attendance {
+    cohorts: Vec<Cohort>,
+}
+
+impl Attendance {
+    /// Load the cohorts, returns `None` if the file does not exist.
+    pub(crate) fn from_file(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read attendance file: {}", path.display()))?;
+        Ok(Some(Self::parse(&content)))
+    }
+
+    /// Parse table rows, the header and malformed rows are skipped.
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut cohorts = Vec::new();
+
+        for line in content.lines().map(str::trim) {
+            if !line.starts_with('|') {
+                continue;
+            }
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+            let [name, people, arrival, departure] = cells[..] else {
+                continue;
+            };
+            let Ok(people) = people.parse() else {
+                continue;
+            };
+            cohorts.push(Cohort {
+                name: name.to_string(),
+                people,
+                arrival: Stop::parse(arrival),
+                departure: Stop::parse(departure),
+            });
+        }
+
+        Self { cohorts }
+    }
+
+    /// Place the cohorts in a plan starting on `start` with the given meals.
+    ///
+    /// Cohorts with an unknown day or a departure before their arrival are
+    /// left out, unknown meals count as the whole day. Meals are only checked
+    /// if the plan names them.
+    pub(crate) fn schedule(&self, start: NaiveDate, meals: &[String]) -> (Schedule, Vec<Issue>) {
+        let mut stays = Vec::new();
+        let mut issues = Vec::new();
+
+        for cohort in &self.cohorts {
+            let arrival = cohort.arrival.date(start);
+            let departure = arrival.and_then(|arrival| {
+                cohort
+                    .departure
+                    .date(arrival)
+                    .filter(|departure| *departure >= arrival)
+            });
+            let (Some(arrival), Some(departure)) = (arrival, departure) else {
+                let stop = match arrival {
+                    Some(_) => &cohort.departure,
+                    None => &cohort.arrival,
+                };
+                issues.push(Issue::UnknownCohortDay {
+                    cohort: cohort.name.clone(),
+                    day: stop.day.clone(),
+                });
+                continue;
+            };
+            stays.push(Stay {
+                people: cohort.people,
+                arrival: (arrival, meal(cohort, &cohort.arrival, meals, &mut issues)),
+                departure: (
+                    departure,
+                    meal(cohort, &cohort.departure, meals, &mut issues),
+                ),
+            });
+        }
+
+        (Schedule(stays), issues)
+    }
+}
+
+/// Index of the meal a cohort arrives or leaves at, unknown meals are reported.
+fn meal(cohort: &Cohort, stop: &Stop, meals: &[String], issues: &mut Vec<Issue>) -> Option<usize> {
+    let name = stop.meal.as_deref()?;
+    if meals.is_empty() {
+        return None;
+    }
+    let index = meals
+        .iter()
+        .position(|meal| meal.eq_ignore_ascii_case(name));
+    if index.is_none() {
+        issues.push(Issue::UnknownCohortMeal {
+            cohort: cohort.name.clone(),
+            meal: name.to_string(),
+        });
+    }
+    index
+}
+
+/// Days and meals of a cohort's stay.
+#[derive(Debug, Clone, PartialEq)]
+struct Stay {
+    people: usize,
+    /// Day and index of the first meal.
+    arrival: (NaiveDate, Option<usize>),
+    /// Day and index of the last meal.
+    departure: (NaiveDate, Option<usize>),
+}
+
+/// The cohorts placed in the days and meals of a plan.
+#[derive(Debug, Default)]
+pub(crate) struct Schedule(Vec<Stay>);
+
+impl Schedule {
+    /// Participants present at a meal, given by its index, or on any meal of the day.
+    pub(crate) fn people(&self, date: NaiveDate, meal: Option<usize>) -> usize {
+        self.0
+            .iter()
+            .filter(|stay| {
+                let (arrival, first) = stay.arrival;
+                let (departure, last) = stay.departure;
+                if date < arrival || date > departure {
+                    return false;
+                }
+                let Some(meal) = meal else {
+                    return true;
+                };
+                (date != arrival || first.is_none_or(|first| meal >= first))
+                    && (date != departure || last.is_none_or(|last| meal <= last))
+            })
+            .map(|stay| stay.people)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 4, day).unwrap()
+    }
+
+    const CONTENT: &str = "# Anwesenheit\n\n\
+        | Gruppe  | Personen | Anreise               | Abreise             |\n\
+        | ------- | -------- | --------------------- | ------------------- |\n\
+        | Kinder  | 30       | Donnerstag Abendessen | Sonntag Frühstück   |\n\
+        | Leitung | 8        | 02.04.                | Sonntag Mittagessen |\n\
+        | Küche   | 5        | Donnerstag            | 2026-04-05          |\n\
+        | Aufbau  | 4        | Mittwoch              | 01.04.2026          |\n\
+        | Gäste   | 3        | Freitag Brunch        | Freitag             |\n\
+        | Besuch  | 2        | irgendwann            | Sonntag             |\n";
+
+    #[test]
+    fn test_parse_cohorts() {
+        let attendance = Attendance::parse(CONTENT);
+
+        assert_eq!(attendance.cohorts.len(), 6);
+        assert_eq!(
+            attendance.cohorts[0],
+            Cohort {
+                name: "Kinder".to_string(),
+                people: 30,
+                arrival: Stop {
+                    day: "Donnerstag".to_string(),
+                    meal: Some("Abendessen".to_string()),
+                },
+                departure: Stop {
+                    day: "Sonntag".to_string(),
+                    meal: Some("Frühstück".to_string()),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_schedule_head_counts() {
+        let meals = ["Frühstück", "Mittagessen", "Abendessen"].map(String::from);
+        // 02.04.2026 is a Thursday, the Aufbau would arrive the Wednesday after.
+        let (schedule, issues) = Attendance::parse(CONTENT).schedule(date(2), &meals);
+
+        assert_eq!(
+            issues
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "cohort 'Aufbau' has unknown day '01.04.2026', leaving it out",
+                "cohort 'Gäste' has unknown meal 'Brunch', counting the whole day",
+                "cohort 'Besuch' has unknown day 'irgendwann', leaving it out",
+            ]
+        );
+
+        assert_eq!(schedule.people(date(2), Some(0)), 13);
+        assert_eq!(schedule.people(date(2), Some(2)), 43);
+        assert_eq!(schedule.people(date(2), None), 43);
+        assert_eq!(schedule.people(date(3), Some(0)), 46);
+        assert_eq!(schedule.people(date(5), Some(0)), 43);
+        assert_eq!(schedule.people(date(5), Some(1)), 13);
+        assert_eq!(schedule.people(date(5), Some(2)), 5);
+        assert_eq!(schedule.people(date(6), None), 0);
+
+        let (schedule, issues) = Attendance::parse(CONTENT).schedule(date(2), &[]);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(schedule.people(date(2), None), 43);
+    }
+}
@@ -0,0 +1,297 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+
+/// Section for ingredients without a known category.
+pub(crate) const UNSORTED: &str = "Unsortiert";
+
+/// Store sections and keywords of ingredients found in them.
+///
+/// A keyword matches ingredients where it ends a word or compound, ignoring
+/// case and plural endings, so "Vollmilch" and "Zwiebeln" match but
+/// "Wassermelone" does not match "wasser". The longest matching keyword wins,
+/// so "Kokosmilch" ends up in "Dosen" even though it also ends in "milch".
+const BUILTIN: &[(&str, &[&str])] = &[
+    (
+        "Frisches",
+        &[
+            "apfel",
+            "äpfel",
+            "aubergine",
+            "banane",
+            "birne",
+            "brokkoli",
+            "champignon",
+            "erdbeere",
+            "fenchel",
+            "gurke",
+            "heidelbeere",
+            "ingwer",
+            "karotte",
+            "kartoffel",
+            "knoblauch",
+            "lauch",
+            "limette",
+            "möhre",
+            "orange",
+            "paprika",
+            "petersilie",
+            "pilze",
+            "salat",
+            "schalotte",
+            "spinat",
+            "tomate",
+            "weintraube",
+            "zitrone",
+            "zucchini",
+            "zwiebel",
+        ],
+    ),
+    (
+        "Kühlregal",
+        &[
+            "butter",
+            "eier",
+            "feta",
+            "frischkäse",
+            "joghurt",
+            "käse",
+            "maultaschen",
+            "milch",
+            "mozzarella",
+            "quark",
+            "ricotta",
+            "sahne",
+            "schmand",
+            "tofu",
+            "wurst",
+        ],
+    ),
+    (
+        "Trockenwaren",
+        &[
+            "brot",
+            "brötchen",
+            "bulgur",
+            "couscous",
+            "gnocchi",
+            "haferflocken",
+            "linsen",
+            "müsli",
+            "nudeln",
+            "reis",
+            "spätzle",
+            "tagliatelle",
+        ],
+    ),
+    (
+        "Backen",
+        &[
+            "backpulver",
+            "kakao",
+            "mandeln",
+            "mehl",
+            "nüsse",
+            "pinienkerne",
+            "schokolade",
+            "vanillezucker",
+            "walnüsse",
+            "zucker",
+        ],
+    ),
+    (
+        "Dosen",
+        &[
+            "apfelmus",
+            "kichererbsen",
+            "kokosmilch",
+            "mais",
+            "marmelade",
+            "passierte",
+            "stückige tomaten",
+            "tomatenmark",
+        ],
+    ),
+    (
+        "Gewürze",
+        &[
+            "chili",
+            "curry",
+            "essig",
+            "kreuzkümmel",
+            "lorbeer",
+            "muskat",
+            "oregano",
+            "öl",
+            "paprikapulver",
+            "pfeffer",
+            "salz",
+            "senf",
+            "sojasauce",
+            "zimt",
+        ],
+    ),
+    ("Getränke", &["kaffee", "saft", "tee", "wasser"]),
+    ("Tiefkühl", &["tk", "tiefgefroren"]),
+];
+
+/// A single section with its keywords.
+#[derive(Debug, Clone, PartialEq)]
+struct Section {
+    name: String,
+    keywords: Vec<String>,
+}
+
+/// Database mapping ingredients to store sections.
+///
+/// User overrides are read from a markdown file using the same layout as the
+/// shopping list:
+///
+/// ```text
+/// ## Kühlregal
+/// - Schinkenalternative
+/// ```
+///
+/// Overrides are matched before the built-in table.
+#[derive(Debug)]
+pub(crate) struct Categories {
+    overrides: Vec<Section>,
+    builtin: Vec<Section>,
+}
+
+impl Default for Categories {
+    fn default() -> Self {
+        let builtin = BUILTIN
+            .iter()
+            .map(|(name, keywords)| Section {
+                name: name.to_string(),
+                keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            })
+            .collect();
+
+        Self {
+            overrides: Vec::new(),
+            builtin,
+        }
+    }
+}
+
+impl Categories {
+    /// Load user overrides, a missing file yields only the built-in table.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read category file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut categories = Self::default();
+
+        for line in content.lines().map(str::trim) {
+            if let Some(name) = line.strip_prefix('#') {
+                categories.overrides.push(Section {
+                    name: name.trim_start_matches('#').trim().to_string(),
+                    keywords: Vec::new(),
+                });
+            } else if let Some(keyword) = line.strip_prefix("- ") {
+                if let Some(section) = categories.overrides.last_mut() {
+                    section.keywords.push(keyword.trim().to_lowercase());
+                }
+            }
+        }
+
+        categories
+    }
+
+    /// The section an ingredient belongs to.
+    pub(crate) fn section(&self, ingredient: &str) -> &str {
+        find(&self.overrides, ingredient)
+            .or_else(|| find(&self.builtin, ingredient))
+            .unwrap_or(UNSORTED)
+    }
+
+    /// All section names in output order, ending with [`UNSORTED`].
+    pub(crate) fn order(&self) -> Vec<&str> {
+        let mut order: Vec<&str> = Vec::new();
+        for section in self.builtin.iter().chain(&self.overrides) {
+            if !order.contains(&section.name.as_str()) {
+                order.push(&section.name);
+            }
+        }
+        order.push(UNSORTED);
+        order
+    }
+}
+
+/// Endings a keyword may carry in an ingredient name, e.g. the plural in "Zwiebeln".
+const ENDINGS: &[&str] = &["", "e", "n", "en", "s", "er"];
+
+/// Section of the longest keyword matching the ingredient name.
+fn find<'a>(sections: &'a [Section], ingredient: &str) -> Option<&'a str> {
+    let ingredient = ingredient.to_lowercase();
+    sections
+        .iter()
+        .flat_map(|section| section.keywords.iter().map(move |k| (section, k)))
+        .filter(|(_, keyword)| matches(&ingredient, keyword))
+        .max_by_key(|(_, keyword)| keyword.chars().count())
+        .map(|(section, _)| section.name.as_str())
+}
+
+/// Whether the keyword ends a word or compound of the name, up to an ending.
+fn matches(name: &str, keyword: &str) -> bool {
+    name.match_indices(keyword).any(|(start, _)| {
+        let rest = &name[start + keyword.len()..];
+        ENDINGS.iter().any(|ending| {
+            rest.strip_prefix(ending)
+                .is_some_and(|rest| !rest.starts_with(char::is_alphabetic))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_sections() {
+        let categories = Categories::default();
+
+        assert_eq!(categories.section("Zwiebel"), "Frisches");
+        assert_eq!(categories.section("Butter"), "Kühlregal");
+        assert_eq!(categories.section("Kokosmilch"), "Dosen");
+        assert_eq!(categories.section("Risotto Reis"), "Trockenwaren");
+        assert_eq!(categories.section("Frischkäse"), "Kühlregal");
+        assert_eq!(categories.section("Seitan"), UNSORTED);
+    }
+
+    #[test]
+    fn test_keywords_match_words_and_compound_suffixes() {
+        let categories = Categories::default();
+
+        assert_eq!(categories.section("Zwiebeln"), "Frisches");
+        assert_eq!(categories.section("Cherrytomaten"), "Frisches");
+        assert_eq!(categories.section("Vollmilch"), "Kühlregal");
+        assert_eq!(categories.section("Milchreis"), "Trockenwaren");
+        assert_eq!(categories.section("TK-Erbsen"), "Tiefkühl");
+        assert_eq!(categories.section("Preiselbeeren"), UNSORTED);
+        assert_eq!(categories.section("Wassermelone"), UNSORTED);
+        assert_eq!(categories.section("Reisessig"), "Gewürze");
+    }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let categories = Categories::parse("## Kühlregal\n- Seitan\n\n## Frühstück\n- Butter\n");
+
+        assert_eq!(categories.section("Seitan"), "Kühlregal");
+        assert_eq!(categories.section("vegane Butter"), "Frühstück");
+        assert_eq!(categories.section("Zwiebel"), "Frisches");
+
+        let order = categories.order();
+        assert_eq!(order.first(), Some(&"Frisches"));
+        assert!(order.contains(&"Frühstück"));
+        assert_eq!(order.last(), Some(&UNSORTED));
+    }
+}
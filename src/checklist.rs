@@ -0,0 +1,407 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    aliases::Aliases,
+    pantry,
+    types::{checked_md_item, Ingredient, IngredientList},
+    units::convert,
+};
+
+/// Suffix of items that are no longer part of the plan.
+const DROPPED: &str = "entfällt";
+
+/// Sub headings of generated blocks that hold no items.
+const GENERATED: &[&str] = &["Kosten", "Haltbarkeit", pantry::HEADING];
+
+/// A generated item of a previous shopping list.
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+    checked: bool,
+    name: String,
+    /// Amount that was or will be bought, whole packages if rounded.
+    amount: f32,
+    measure: String,
+    dish: String,
+}
+
+/// Items of a single Einkauf of a previous shopping list.
+#[derive(Debug, Default)]
+struct Trip {
+    entries: Vec<Entry>,
+    /// Lines that were not generated, kept verbatim.
+    manual: Vec<String>,
+}
+
+/// A previously generated shopping list with the items already ticked off.
+///
+/// Items are matched per trip heading, e.g. "## Großmarkt Mi", by their
+/// canonical ingredient name. Cost blocks and sub headings are skipped, every
+/// other line that does not look like a generated item is kept as a manual
+/// entry.
+#[derive(Debug, Default)]
+pub(crate) struct Checklist {
+    trips: HashMap<String, Trip>,
+}
+
+impl Checklist {
+    /// Load the previous list, a missing file yields an empty list.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read shopping list: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut checklist = Self::default();
+        let mut trip: Option<&mut Trip> = None;
+        let mut skipping = false;
+
+        for line in content.lines().map(str::trim_end) {
+            if let Some(heading) = line.strip_prefix("## ") {
+                let heading = heading.trim();
+                skipping = false;
+                trip = (heading != "Gesamtkosten")
+                    .then(|| checklist.trips.entry(heading.to_string()).or_default());
+                continue;
+            }
+            if let Some(heading) = line.strip_prefix('#') {
+                let heading = heading.trim_start_matches('#').trim();
+                skipping = GENERATED.contains(&heading);
+                continue;
+            }
+            let Some(trip) = trip.as_deref_mut() else {
+                continue;
+            };
+            if skipping
+                || line.trim().is_empty()
+                || line.starts_with("**")
+                || line.starts_with("Ohne Preis:")
+            {
+                continue;
+            }
+            match parse_entry(line) {
+                Some(entry) => trip.entries.push(entry),
+                None => trip.manual.push(line.to_string()),
+            }
+        }
+
+        checklist
+    }
+
+    /// Carry the checked state of an Einkauf over to its new, accumulated list.
+    ///
+    /// Bought amounts are taken off the list, so it only keeps what is still
+    /// missing. Checked items that do not match anything anymore are flagged.
+    /// Unchecked ones are simply dropped.
+    pub(crate) fn reconcile(
+        &self,
+        label: &str,
+        list: &mut IngredientList,
+        aliases: &Aliases,
+    ) -> Progress {
+        let Some(trip) = self.trips.get(label) else {
+            return Progress::default();
+        };
+
+        let mut used = vec![false; trip.entries.len()];
+        let mut done = Vec::new();
+
+        for ingredient in list.0.iter_mut() {
+            let name = aliases.canonical(&ingredient.name).to_lowercase();
+            let mut bought = 0.0;
+            for (entry, used) in trip.entries.iter().zip(used.iter_mut()) {
+                if aliases.canonical(&entry.name).to_lowercase() != name {
+                    continue;
+                }
+                let Some(amount) = convert(entry.amount, &entry.measure, &ingredient.measure)
+                else {
+                    continue;
+                };
+                *used = true;
+                if entry.checked {
+                    bought += amount;
+                }
+            }
+            if bought > 0.0 {
+                done.push(Ingredient {
+                    amount: bought,
+                    ..ingredient.clone()
+                });
+                ingredient.amount -= bought;
+            }
+        }
+        list.0.retain(|ingredient| ingredient.amount > 1e-4);
+
+        let dropped = trip
+            .entries
+            .iter()
+            .zip(used)
+            .filter(|(entry, used)| entry.checked && !used)
+            .map(|(entry, _)| entry.clone())
+            .collect();
+
+        Progress {
+            done,
+            dropped,
+            manual: trip.manual.clone(),
+        }
+    }
+}
+
+/// What was already done for an Einkauf.
+#[derive(Debug, Default)]
+pub(crate) struct Progress {
+    /// Bought amounts of items still in the plan.
+    pub(crate) done: Vec<Ingredient>,
+    /// Bought items that are no longer in the plan.
+    dropped: Vec<Entry>,
+    /// Manually added lines.
+    manual: Vec<String>,
+}
+
+impl Progress {
+    /// Whether a manual line was carried over from the previous list.
+    pub(crate) fn has_manual(&self, line: &str) -> bool {
+        self.manual
+            .iter()
+            .any(|manual| item_text(manual) == item_text(line))
+    }
+
+    /// Blocks for done, dropped and manual items, empty if there are none.
+    pub(crate) fn as_markdown(&self) -> String {
+        let mut blocks = Vec::new();
+
+        if !self.done.is_empty() {
+            let mut items: Vec<String> = self.done.iter().map(checked_md_item).collect();
+            items.sort();
+            blocks.push(format!("### Erledigt\n{}", items.join("\n")));
+        }
+        if !self.dropped.is_empty() {
+            let items: Vec<String> = self
+                .dropped
+                .iter()
+                .map(|entry| {
+                    let amount = if entry.measure.is_empty() {
+                        format!("{:.1}", entry.amount)
+                    } else {
+                        format!("{:.1} {}", entry.amount, entry.measure)
+                    };
+                    format!(
+                        "- [x] ~~{}: {} ({})~~ {DROPPED}",
+                        entry.name, amount, entry.dish
+                    )
+                })
+                .collect();
+            blocks.push(format!("### Nicht mehr im Plan\n{}", items.join("\n")));
+        }
+        if !self.manual.is_empty() {
+            blocks.push(format!("### Eigene Einträge\n{}", self.manual.join("\n")));
+        }
+
+        blocks.join("\n\n")
+    }
+}
+
+/// Parse a generated item like `- [x] Butter: 5 × 250 g (benötigt 1.1 kg) (Kuchen)`.
+fn parse_entry(line: &str) -> Option<Entry> {
+    let (checked, item) = if let Some(item) = line.strip_prefix("- [ ] ") {
+        (false, item)
+    } else if let Some(item) = line
+        .strip_prefix("- [x] ")
+        .or_else(|| line.strip_prefix("- [X] "))
+    {
+        (true, item)
+    } else {
+        return None;
+    };
+    let item = item.trim_end_matches(DROPPED).trim_end();
+    let item = item
+        .strip_prefix("~~")
+        .and_then(|item| item.strip_suffix("~~"))
+        .unwrap_or(item);
+
+    let (rest, dish) = split_last_parenthesis(item)?;
+    let (name, quantity) = rest.split_once(": ")?;
+    let (amount, measure) = parse_quantity(quantity)?;
+
+    Some(Entry {
+        checked,
+        name: name.trim().to_string(),
+        amount,
+        measure,
+        dish: dish.to_string(),
+    })
+}
+
+/// Text of a list line without marker and checkbox.
+fn item_text(line: &str) -> &str {
+    let line = line.trim();
+    ["- [ ] ", "- [x] ", "- [X] ", "- "]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))
+        .unwrap_or(line)
+        .trim()
+}
+
+/// Split `text (inner)` at its last balanced parenthesis.
+fn split_last_parenthesis(text: &str) -> Option<(&str, &str)> {
+    let text = text.strip_suffix(')')?;
+    let mut depth = 0;
+    for (i, c) in text.char_indices().rev() {
+        match c {
+            ')' => depth += 1,
+            '(' if depth == 0 => return Some((text[..i].trim_end(), &text[i + 1..])),
+            '(' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Amount and measure of `21.5`, `6200.0 g` or `5 × 250 g (benötigt 1.1 kg)`.
+fn parse_quantity(quantity: &str) -> Option<(f32, String)> {
+    let quantity = match quantity.split_once(" (benötigt") {
+        Some((quantity, _)) => quantity,
+        None => quantity,
+    };
+    let (count, quantity) = match quantity.split_once(" × ") {
+        Some((count, size)) => (count.trim().parse::<f32>().ok()?, size),
+        None => (1.0, quantity),
+    };
+    let (amount, measure) = match quantity.trim().split_once(' ') {
+        Some((amount, measure)) => (amount, measure.trim()),
+        None => (quantity.trim(), ""),
+    };
+    let amount: f32 = amount.replace(',', ".").parse().ok()?;
+    Some((count * amount, measure.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_ingredient(amount: f32, measure: &str, name: &str, dish: &str) -> Ingredient {
+        Ingredient {
+            amount,
+            measure: measure.to_string(),
+            name: name.to_string(),
+            dish: dish.to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    const PREVIOUS: &str = r#"## Einkauf 1
+
+### Frisches
+- [x] Möhren: 21.5 (Mie Nudeln)
+- [ ] Paprika: 3.0 (Mie Nudeln)
+- [x] Butter: 2 × 250 g (benötigt 400.0 g) (Käsespätzle (vegan))
+- [x] Mais: 2.0 Dose (Chili)
+- [x] Salz
+- Milch für Kaffee (Hafer + Normal)
+
+### Kosten
+- Butter: 4,40 €
+
+**Summe Einkauf 1: 4,40 €**
+
+## Einkauf 2
+
+- [x] Zwiebel: 3.0 (Curry)
+
+## Großmarkt Mi
+
+**Einkauf am Mittwoch, 01.04.2026**
+
+- [ ] Salat: 2.0 (Salatteller)
+
+### Aus dem Lager
+- Nudeln: 500.0 g (Salatteller)
+
+### Haltbarkeit
+- Salat für Salatteller am Dienstag, 07.04.2026: 6 Tage nach dem Einkauf (Frisches hält etwa 4 Tage)
+
+## Gesamtkosten
+
+**4,40 €**
+"#;
+
+    #[test]
+    fn test_parse_entries_and_manual_lines() {
+        let checklist = Checklist::parse(PREVIOUS);
+
+        let trip = &checklist.trips["Einkauf 1"];
+        assert_eq!(trip.entries.len(), 4);
+        assert_eq!(
+            trip.entries[2],
+            Entry {
+                checked: true,
+                name: "Butter".to_string(),
+                amount: 500.0,
+                measure: "g".to_string(),
+                dish: "Käsespätzle (vegan)".to_string(),
+            }
+        );
+        assert!(!trip.entries[1].checked);
+        assert_eq!(
+            trip.manual,
+            vec!["- [x] Salz", "- Milch für Kaffee (Hafer + Normal)"]
+        );
+        assert_eq!(checklist.trips["Einkauf 2"].entries.len(), 1);
+        let market = &checklist.trips["Großmarkt Mi"];
+        assert_eq!(market.entries.len(), 1);
+        assert!(market.manual.is_empty());
+        assert_eq!(checklist.trips.len(), 3);
+    }
+
+    #[test]
+    fn test_reconcile_carries_checked_state() {
+        let checklist = Checklist::parse(PREVIOUS);
+        let mut list = IngredientList::from(vec![
+            make_ingredient(20.0, "", "Möhren", "Mie Nudeln"),
+            make_ingredient(3.0, "", "Paprika", "Mie Nudeln"),
+            make_ingredient(0.7, "kg", "Butter", "Käsespätzle"),
+        ]);
+
+        let progress = checklist.reconcile("Einkauf 1", &mut list, &Aliases::default());
+
+        // Möhren are covered, Butter only shows the missing 200 g.
+        assert_eq!(list.0.len(), 2);
+        assert_eq!(list.0[0].name, "Paprika");
+        assert_eq!(list.0[1].name, "Butter");
+        assert!((list.0[1].amount - 0.2).abs() < 1e-4);
+
+        assert_eq!(progress.done.len(), 2);
+        assert_eq!(progress.done[0].amount, 21.5);
+
+        let markdown = progress.as_markdown();
+        assert!(markdown.contains("### Erledigt\n- [x] Butter: 0.5 kg (Käsespätzle)"));
+        assert!(markdown.contains("- [x] Möhren: 21.5 (Mie Nudeln)"));
+        assert!(markdown.contains("- [x] ~~Mais: 2.0 Dose (Chili)~~ entfällt"));
+        assert!(markdown.contains("### Eigene Einträge\n- [x] Salz"));
+        assert!(progress.has_manual("- [ ] Salz"));
+        assert!(!progress.has_manual("- [ ] Pfeffer"));
+    }
+
+    #[test]
+    fn test_reconcile_round_trip() {
+        let checklist = Checklist::parse(PREVIOUS);
+        let mut list = IngredientList::new();
+        let progress = checklist.reconcile("Einkauf 1", &mut list, &Aliases::default());
+
+        let regenerated = Checklist::parse(&format!("## Einkauf 1\n\n{}", progress.as_markdown()));
+        let trip = &regenerated.trips["Einkauf 1"];
+        assert_eq!(trip.entries.len(), 3);
+        assert!(trip.entries.iter().all(|entry| entry.checked));
+        assert_eq!(trip.manual.len(), 2);
+
+        let unknown = checklist.reconcile("Einkauf 3", &mut list, &Aliases::default());
+        assert!(unknown.as_markdown().is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 use clap::Parser;
 use std::path::PathBuf;
 
+use crate::{extras::ExtraSource, quantity::RangePolicy};
+
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
 pub(crate) struct Cli {
@@ -11,4 +13,89 @@ pub(crate) struct Cli {
     /// Root path under which all dishes can be found.
     #[arg(short, long, default_value = "./")]
     pub dish_root: PathBuf,
+
+    /// File mapping variant ingredient names to canonical ones.
+    #[arg(short, long, default_value = "./aliases.md")]
+    pub aliases: PathBuf,
+
+    /// File with vegan substitutes, one `Original: Ersatz` per line.
+    #[arg(long, default_value = "./substitutions.md")]
+    pub substitutions: PathBuf,
+
+    /// Markdown file assigning ingredients to store sections.
+    #[arg(long, default_value = "./categories.md")]
+    pub categories: PathBuf,
+
+    /// Markdown table with nutrient values per 100 g of each ingredient.
+    #[arg(long, default_value = "./nutrition.md")]
+    pub nutrition: PathBuf,
+
+    /// File with the allergens of each ingredient, e.g. "Butter: Milch".
+    #[arg(long, default_value = "./allergens.md")]
+    pub allergens: PathBuf,
+
+    /// Markdown table with the price and size of a package of each ingredient.
+    #[arg(long, default_value = "./prices.md")]
+    pub prices: PathBuf,
+
+    /// Markdown file with package sizes and rounding steps for count units.
+    #[arg(long, default_value = "./packages.md")]
+    pub packages: PathBuf,
+
+    /// File with scaling rules for ingredients that do not scale linearly, e.g. "Salz: sublinear 0,7".
+    #[arg(long, default_value = "./scaling.md")]
+    pub scaling: PathBuf,
+
+    /// Markdown table of additional units with plural and abbreviations, e.g. "| Zehe | Zehen | |".
+    #[arg(long, default_value = "./units.md")]
+    pub units: PathBuf,
+
+    /// Markdown table of cohorts with arrival and departure, for the head count of each day and meal.
+    #[arg(long, default_value = "./attendance.md")]
+    pub attendance: PathBuf,
+
+    /// Markdown table with the stock left in the storeroom, taken off the shopping lists.
+    #[arg(long, default_value = "./pantry.md")]
+    pub pantry: PathBuf,
+
+    /// Hand-written list to merge, optionally into a named Einkauf: "extrawünsche.md=Einkauf 2".
+    #[arg(long = "extra", value_name = "FILE[=EINKAUF]")]
+    pub extras: Vec<ExtraSource>,
+
+    /// Amount used for quantity ranges like "2-3 EL" in recipes.
+    #[arg(long, value_enum, default_value_t = RangePolicy::Mean)]
+    pub ranges: RangePolicy,
+
+    /// Group each shopping list by store section.
+    #[arg(long)]
+    pub clustered: bool,
+
+    /// Regenerate shopping-list.md without carrying over checked items.
+    #[arg(long)]
+    pub fresh: bool,
+
+    /// Print the head count of each day and meal.
+    #[arg(long)]
+    pub head_counts: bool,
+
+    /// Print the Reste meals with the dishes whose surplus feeds them.
+    #[arg(long)]
+    pub leftovers: bool,
+
+    /// Print a matrix of the allergens of each day's dishes.
+    #[arg(long)]
+    pub allergen_matrix: bool,
+
+    /// Additionally render shopping-list.pdf and dishes.pdf, one section per Einkauf and day without page breaks.
+    #[arg(long)]
+    pub pdf: bool,
+
+    /// Check all dishes for ingredient names that start with a probable unit.
+    #[arg(long)]
+    pub lint_units: bool,
+
+    /// Exit with an error if the plan has unresolved dishes or unparseable recipes,
+    /// or the scaling rules have unknown lines.
+    #[arg(long)]
+    pub strict: bool,
 }
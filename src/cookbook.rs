@@ -4,8 +4,32 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use crate::{
+    aliases::Aliases, allergens::AllergenTable, attendance::Attendance, nutrition::NutrientTable,
+    prices::Prices, quantity::RangePolicy, scaling::ScalingRules, substitutions::Substitutions,
+    units::UnitRegistry,
+};
+
 pub(crate) struct CookBook {
     dishes: HashMap<String, PathBuf>,
+    /// Vegan substitutes used for vegan portions.
+    pub(crate) substitutions: Substitutions,
+    /// Canonical ingredient names.
+    pub(crate) aliases: Aliases,
+    /// Nutrient values, nutrition is only computed if present.
+    pub(crate) nutrients: Option<NutrientTable>,
+    /// Allergen table, dishes are only labelled if present.
+    pub(crate) allergens: Option<AllergenTable>,
+    /// Price catalog, costs are only estimated if present.
+    pub(crate) prices: Option<Prices>,
+    /// Amount used for quantity ranges in recipes.
+    pub(crate) ranges: RangePolicy,
+    /// Ingredients that do not scale linearly with the people.
+    pub(crate) scaling: ScalingRules,
+    /// Cohorts the head counts of days and meals are derived from, if present.
+    pub(crate) attendance: Option<Attendance>,
+    /// Units recognized in ingredient lines.
+    pub(crate) units: UnitRegistry,
 }
 
 /// Collect all dishes recursively from the given path.
@@ -32,7 +56,83 @@ impl CookBook {
             })
             .collect::<HashMap<_, _>>();
 
-        Self { dishes }
+        Self {
+            dishes,
+            substitutions: Substitutions::default(),
+            aliases: Aliases::default(),
+            nutrients: None,
+            allergens: None,
+            prices: None,
+            ranges: RangePolicy::default(),
+            scaling: ScalingRules::default(),
+            attendance: None,
+            units: UnitRegistry::default(),
+        }
+    }
+
+    /// Replace the vegan substitution table.
+    pub(crate) fn with_substitutions(mut self, substitutions: Substitutions) -> Self {
+        self.substitutions = substitutions;
+        self
+    }
+
+    /// Replace the ingredient alias dictionary.
+    pub(crate) fn with_aliases(mut self, aliases: Aliases) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Set the nutrient table.
+    pub(crate) fn with_nutrients(mut self, nutrients: Option<NutrientTable>) -> Self {
+        self.nutrients = nutrients;
+        self
+    }
+
+    /// Set the allergen table.
+    pub(crate) fn with_allergens(mut self, allergens: Option<AllergenTable>) -> Self {
+        self.allergens = allergens;
+        self
+    }
+
+    /// Set the price catalog.
+    pub(crate) fn with_prices(mut self, prices: Option<Prices>) -> Self {
+        self.prices = prices;
+        self
+    }
+
+    /// Set the amount used for quantity ranges.
+    pub(crate) fn with_range_policy(mut self, ranges: RangePolicy) -> Self {
+        self.ranges = ranges;
+        self
+    }
+
+    /// Replace the scaling rule table.
+    pub(crate) fn with_scaling(mut self, scaling: ScalingRules) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    /// Set the attendance cohorts.
+    pub(crate) fn with_attendance(mut self, attendance: Option<Attendance>) -> Self {
+        self.attendance = attendance;
+        self
+    }
+
+    /// Replace the unit registry.
+    pub(crate) fn with_units(mut self, units: UnitRegistry) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// All dishes with their paths, sorted by name.
+    pub(crate) fn dishes(&self) -> Vec<(&str, &Path)> {
+        let mut dishes: Vec<(&str, &Path)> = self
+            .dishes
+            .iter()
+            .map(|(name, path)| (name.as_str(), path.as_path()))
+            .collect();
+        dishes.sort();
+        dishes
     }
 
     /// Get a dish path by name.
@@ -0,0 +1,297 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::plan::weekday_name;
+
+/// A 1-based line/column position in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Position {
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl From<tree_sitter::Point> for Position {
+    fn from(point: tree_sitter::Point) -> Self {
+        Self {
+            line: point.row + 1,
+            column: point.column + 1,
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Something that went wrong while resolving a plan.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Issue {
+    /// The dish is referenced by the plan but not part of the cookbook.
+    MissingDish { dish: String },
+    /// The dish file exists but could not be parsed.
+    DishParse { dish: String, error: String },
+    /// An ingredient was dropped because its quantity could not be read.
+    UnparseableQuantity {
+        dish: String,
+        ingredient: String,
+        quantity: String,
+        /// Position inside the dish file.
+        at: Position,
+    },
+    /// A line of the dish file was left out because it could not be parsed.
+    UnreadableLine {
+        dish: String,
+        text: String,
+        /// Position inside the dish file.
+        at: Position,
+    },
+    /// The weekday written in the plan does not match the date counted from the start day.
+    WeekdayMismatch { day: String, date: NaiveDate },
+    /// Dishes referencing each other as ingredients, the first dish repeats at the end.
+    SubDishCycle { chain: Vec<String> },
+    /// A sub-dish is requested in a measure its yield cannot be compared to.
+    UnknownYield { dish: String, measure: String },
+    /// Pieces requested in a unit the dish does not yield.
+    UnknownBatchUnit { dish: String, unit: String },
+    /// A dish reference option like `|vgan` that is neither an option nor a unit of the dish.
+    UnknownDishOption { dish: String, option: String },
+    /// An ingredient name that starts with something that looks like a unit,
+    /// only reported by the unit lint.
+    ProbableUnit {
+        dish: String,
+        ingredient: String,
+        unit: String,
+        /// Position inside the dish file.
+        at: Position,
+    },
+    /// An ingredient annotation that is not a known scaling rule.
+    UnknownScaling {
+        dish: String,
+        ingredient: String,
+        rule: String,
+    },
+    /// A line of the scaling rule table that is not an ingredient with a known rule.
+    UnknownScalingRule { line: String },
+    /// A diet tag of a dish or participant group that is not known.
+    UnknownDiet { dish: Option<String>, diet: String },
+    /// A participant group without a dish that is known to fit its diet.
+    UnservedGroup {
+        meal: String,
+        diet: String,
+        people: usize,
+    },
+    /// An attendance cohort arrives or leaves on a day outside the plan's reach.
+    UnknownCohortDay { cohort: String, day: String },
+    /// An attendance cohort arrives or leaves at a meal the plan does not have.
+    UnknownCohortMeal { cohort: String, meal: String },
+    /// The attendance peaks at a different head count than the plan header.
+    AttendanceMismatch { people: usize, attendance: usize },
+    /// A day or meal without participants, its dishes are scaled to nothing.
+    NobodyPresent { meal: String },
+    /// A Reste meal without enough planned surplus from earlier dishes.
+    UncoveredRestMeal {
+        meal: String,
+        portions: usize,
+        people: usize,
+    },
+    /// Planned surplus of a dish that no later Reste meal eats.
+    UneatenSurplus { dish: String, portions: usize },
+    /// Ingredients of the dish without an entry in the nutrient table.
+    MissingNutrients {
+        dish: String,
+        ingredients: Vec<String>,
+    },
+    /// Ingredients of the dish without an entry in the allergen table.
+    MissingAllergens {
+        dish: String,
+        ingredients: Vec<String>,
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::MissingDish { dish } => write!(f, "dish [[{dish}]] not found in cookbook"),
+            Issue::DishParse { dish, error } => {
+                write!(f, "dish [[{dish}]] failed to load: {error}")
+            }
+            Issue::UnparseableQuantity {
+                dish,
+                ingredient,
+                quantity,
+                at,
+            } => write!(
+                f,
+                "dish [[{dish}]] line {}: unparseable quantity '{quantity}' for '{ingredient}'",
+                at.line
+            ),
+            Issue::UnreadableLine { dish, text, at } => write!(
+                f,
+                "dish [[{dish}]] line {}: cannot read '{text}', leaving it out",
+                at.line
+            ),
+            Issue::WeekdayMismatch { day, date } => {
+                write!(
+                    f,
+                    "day '{day}' falls on {}, {}",
+                    weekday_name(date.weekday()),
+                    date.format("%d.%m.%Y")
+                )
+            }
+            Issue::SubDishCycle { chain } => write!(
+                f,
+                "dish [[{}]] references itself: {}",
+                chain.last().map_or("", String::as_str),
+                chain.join(" → ")
+            ),
+            Issue::UnknownYield { dish, measure } => write!(
+                f,
+                "dish [[{dish}]] has no yield in '{measure}', using the whole recipe"
+            ),
+            Issue::UnknownBatchUnit { dish, unit } => write!(
+                f,
+                "dish [[{dish}]] does not yield '{unit}', reading the count as persons"
+            ),
+            Issue::UnknownDishOption { dish, option } => {
+                write!(f, "dish [[{dish}]] has unknown option '{option}', ignoring it")
+            }
+            Issue::ProbableUnit {
+                dish,
+                ingredient,
+                unit,
+                at,
+            } => write!(
+                f,
+                "dish [[{dish}]] line {}: '{ingredient}' starts with probable unit '{unit}'",
+                at.line
+            ),
+            Issue::UnknownScaling {
+                dish,
+                ingredient,
+                rule,
+            } => write!(
+                f,
+                "dish [[{dish}]] has unknown scaling '{rule}' for {ingredient}, scaling linearly"
+            ),
+            Issue::UnknownScalingRule { line } => {
+                write!(f, "unknown scaling rule '{line}', ignoring it")
+            }
+            Issue::UnknownDiet {
+                dish: Some(dish),
+                diet,
+            } => write!(f, "dish [[{dish}]] has unknown diet '{diet}', ignoring it"),
+            Issue::UnknownDiet { dish: None, diet } => {
+                write!(f, "unknown participant group '{diet}', ignoring it")
+            }
+            Issue::UnservedGroup { meal, diet, people } => write!(
+                f,
+                "{meal} has no dish known to be {diet} for {people} participants"
+            ),
+            Issue::UnknownCohortDay { cohort, day } => write!(
+                f,
+                "cohort '{cohort}' has unknown day '{day}', leaving it out"
+            ),
+            Issue::UnknownCohortMeal { cohort, meal } => write!(
+                f,
+                "cohort '{cohort}' has unknown meal '{meal}', counting the whole day"
+            ),
+            Issue::AttendanceMismatch { people, attendance } => write!(
+                f,
+                "plan has {people} participants but the attendance up to {attendance}, using the attendance"
+            ),
+            Issue::NobodyPresent { meal } => {
+                write!(f, "{meal} has no participants, scaling its dishes to 0")
+            }
+            Issue::UncoveredRestMeal {
+                meal,
+                portions,
+                people,
+            } => write!(
+                f,
+                "{meal} has leftovers for {portions} of {people} participants"
+            ),
+            Issue::UneatenSurplus { dish, portions } => write!(
+                f,
+                "dish [[{dish}]] has {portions} surplus portions that no Reste meal eats"
+            ),
+            Issue::MissingNutrients { dish, ingredients } => write!(
+                f,
+                "dish [[{dish}]] has no nutrient data for {}",
+                ingredients.join(", ")
+            ),
+            Issue::MissingAllergens { dish, ingredients } => write!(
+                f,
+                "dish [[{dish}]] has no allergen data for {}",
+                ingredients.join(", ")
+            ),
+        }
+    }
+}
+
+/// An issue together with the plan position it originates from.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Diagnostic {
+    pub(crate) at: Position,
+    pub(crate) issue: Issue,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.at, self.issue)
+    }
+}
+
+/// Collection of all diagnostics gathered while loading a plan.
+#[derive(Debug, Default)]
+pub(crate) struct Report(pub(crate) Vec<Diagnostic>);
+
+impl Report {
+    pub(crate) fn push(&mut self, at: Position, issue: Issue) {
+        self.0.push(Diagnostic { at, issue });
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Human readable summary, one diagnostic per line.
+    pub(crate) fn summary(&self) -> String {
+        let mut output = format!("{} problem(s) found in plan:\n", self.0.len());
+        for diagnostic in &self.0 {
+            output.push_str(&format!("  {diagnostic}\n"));
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_from_point_is_one_based() {
+        let position = Position::from(tree_sitter::Point { row: 0, column: 4 });
+        assert_eq!(position, Position { line: 1, column: 5 });
+    }
+
+    #[test]
+    fn test_report_summary() {
+        let mut report = Report::default();
+        report.push(
+            Position {
+                line: 3,
+                column: 11,
+            },
+            Issue::MissingDish {
+                dish: "Gulasch".to_string(),
+            },
+        );
+
+        let summary = report.summary();
+        assert!(summary.contains("1 problem(s)"));
+        assert!(summary.contains("3:11: dish [[Gulasch]] not found in cookbook"));
+    }
+}
@@ -1,19 +1,24 @@
 use std::{
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use tree_sitter::Parser;
 
-use crate::types::Ingredient;
-use thiserror::Error;
-
-#[derive(Error, Debug)]
-pub(crate) enum DishPlanError {
-    #[error("The plan does not exist at the given location.")]
-    DishError,
-}
+use crate::{
+    aliases::Aliases,
+    allergens::{Diet, Labels},
+    cookbook::CookBook,
+    diagnostics::{Issue, Position},
+    nutrition::Nutrition,
+    prices::{euro, Costs},
+    quantity::{Quantity, RangePolicy},
+    scaling::{split_annotation, Scaling, ScalingRules},
+    types::Ingredient,
+    units::{convert, UnitRegistry},
+};
 
 /// A single dish.
 #[derive(Debug)]
@@ -29,11 +34,81 @@ pub(crate) struct Dish {
     /// Other text
     pub(crate) blocks: Vec<String>,
     /// Path
+    #[allow(dead_code)]
     pub(crate) path: PathBuf,
+    /// Problems found while parsing the dish file.
+    pub(crate) issues: Vec<Issue>,
+    /// Portions that should be cooked vegan.
+    pub(crate) vegan: Option<VeganPortions>,
+    /// Nutrients of the scaled dish, if a nutrient table is available.
+    pub(crate) nutrition: Option<Nutrition>,
+    /// Ingredient costs of the scaled dish, if a price catalog is available.
+    pub(crate) costs: Option<Costs>,
+    /// Allergens and diets of the dish, if an allergen table is available.
+    pub(crate) labels: Option<Labels>,
+    /// Diet groups the dish is cooked for, declared as `Ernährung: vegan`.
+    pub(crate) diets: Vec<Diet>,
+    /// Amount one recipe yields, e.g. `(1.5, "l")`, declared as `Ergibt: 1,5 l`.
+    pub(crate) yields: Option<(f32, String)>,
+    /// Yield in pieces, trays or other batches instead of persons.
+    pub(crate) batch: Option<Batch>,
+    /// Pieces requested by the plan, in the unit of the batch.
+    pub(crate) pieces: Option<usize>,
+    /// Share cooked on top for a later Reste meal, e.g. 0.2 for `[[Gulasch|+20%]]`.
+    pub(crate) surplus: Option<f32>,
+    /// Lines of the ingredients section the parser could not read.
+    pub(crate) gaps: Vec<Gap>,
+    /// 1-based line of each ingredient in the dish file.
+    lines: Vec<usize>,
+    /// Non-linear scaling by ingredient name, from annotations or the rule table.
+    pub(crate) scaling: HashMap<String, Scaling>,
+    /// Expanded ingredients of sub-dishes, by index of their reference.
+    components: HashMap<usize, Vec<Ingredient>>,
+}
+
+/// Yield of a recipe that is not counted in persons, e.g. `12 Stück` or `1 Blech`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Batch {
+    /// Pieces one recipe yields.
+    pub(crate) size: usize,
+    /// Unit of the pieces, e.g. "Stück".
+    pub(crate) unit: String,
+    /// Pieces a person eats, declared as `Pro Person: 2`.
+    pub(crate) per_person: f32,
+}
+
+/// A line of a dish file that could not be read.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Gap {
+    /// 1-based line in the dish file.
+    pub(crate) line: usize,
+    /// The line as written, without the list marker.
+    pub(crate) text: String,
+}
+
+/// Vegan share of a dish.
+#[derive(Debug, Default)]
+pub(crate) struct VeganPortions {
+    /// Number of vegan portions, out of the dish's people.
+    pub(crate) people: usize,
+    /// Substitute for each ingredient of the dish that needs one.
+    pub(crate) substitutes: HashMap<String, String>,
 }
 
 impl Dish {
+    /// Load a dish with the built in units.
+    #[cfg(test)]
     pub(crate) fn from_file(path: &Path, dish_name: &str, people: usize) -> Result<Self> {
+        Self::from_file_with_units(path, dish_name, people, &UnitRegistry::default())
+    }
+
+    /// Load a dish, reading the units of its ingredients with `units`.
+    pub(crate) fn from_file_with_units(
+        path: &Path,
+        dish_name: &str,
+        people: usize,
+        units: &UnitRegistry,
+    ) -> Result<Self> {
         let content = fs::read_to_string(path)
             .with_context(|| format!("Failed to read dish file: {}", path.display()))?;
 
@@ -47,14 +122,37 @@ impl Dish {
             .context("Failed to parse dish file")?;
         let root = tree.root_node();
 
-        if root.has_error() {
-            panic!("Parse error in dish file: {}", path.display());
+        // Without a persons line and ingredients there is nothing to recover.
+        if root.is_error() {
+            bail!("Parse error in dish file: {}", path.display());
         }
 
         let mut cursor = root.walk();
         let mut recipe_people = 1;
         let mut ingredients = Vec::new();
+        let mut lines = Vec::new();
+        let mut issues = Vec::new();
+        let mut scaling = HashMap::new();
         let mut preparation_text = String::new();
+        let mut yields = None;
+        let mut batch_unit = None;
+        let mut per_person = None;
+        let mut diets = Vec::new();
+
+        let mut gaps = Vec::new();
+        for (gap, in_ingredients) in unreadable_lines(root, &content) {
+            issues.push(Issue::UnreadableLine {
+                dish: dish_name.to_string(),
+                text: gap.text.clone(),
+                at: Position {
+                    line: gap.line,
+                    column: 1,
+                },
+            });
+            if in_ingredients {
+                gaps.push(gap);
+            }
+        }
 
         for child in root.children(&mut cursor) {
             match child.kind() {
@@ -63,9 +161,42 @@ impl Dish {
                         let count_str = content[count_node.byte_range()].trim();
                         recipe_people = count_str.parse().unwrap_or(1);
                     }
+                    if let Some(unit_node) = child.child_by_field_name("unit") {
+                        batch_unit = Some(content[unit_node.byte_range()].to_string());
+                    }
                 }
                 "ingredients_section" => {
-                    parse_ingredients_section(&child, &content, dish_name, &mut ingredients);
+                    for (line, ingredient) in parse_ingredients_section(
+                        &child,
+                        &content,
+                        units,
+                        dish_name,
+                        &mut scaling,
+                        &mut issues,
+                    ) {
+                        lines.push(line);
+                        ingredients.push(ingredient);
+                    }
+                }
+                "preamble_line" => {
+                    let line = &content[child.byte_range()];
+                    if let Some(declared) = parse_yield(line) {
+                        yields = Some(declared);
+                    }
+                    if let Some(declared) = parse_per_person(line) {
+                        per_person = Some(declared);
+                    }
+                    if let Some(names) = line.trim().strip_prefix("Ernährung:") {
+                        for name in names.split(',').map(str::trim) {
+                            match Diet::parse(name) {
+                                Some(diet) => diets.push(diet),
+                                None => issues.push(Issue::UnknownDiet {
+                                    dish: Some(dish_name.to_string()),
+                                    diet: name.to_string(),
+                                }),
+                            }
+                        }
+                    }
                 }
                 "preparation_section" => {
                     // Extract the full text of the preparation section
@@ -76,6 +207,16 @@ impl Dish {
             }
         }
 
+        let batch = batch_unit.map(|unit| Batch {
+            size: recipe_people,
+            unit,
+            per_person: per_person.unwrap_or(1.0),
+        });
+        if let Some(batch) = &batch {
+            // Persons one recipe feeds, for sub-dish portions and vegan shares.
+            recipe_people = (batch.size as f32 / batch.per_person).round().max(1.0) as usize;
+        }
+
         Ok(Self {
             name: dish_name.to_string(),
             people: Some(people),
@@ -87,51 +228,353 @@ impl Dish {
                 vec![preparation_text]
             },
             path: path.to_path_buf(),
+            issues,
+            vegan: None,
+            nutrition: None,
+            costs: None,
+            labels: None,
+            diets,
+            yields,
+            batch,
+            pieces: None,
+            surplus: None,
+            gaps,
+            lines,
+            scaling,
+            components: HashMap::new(),
         })
     }
 
-    pub(crate) fn shopping_list(&self) -> Vec<Ingredient> {
-        let base = self.recepie_people.max(1) as f32;
-        let target = self.people.unwrap_or(self.recepie_people) as f32;
-        let scale = target / base;
+    /// Request a number of pieces instead of persons, e.g. 42 muffins.
+    ///
+    /// Fails if the dish does not yield that unit, the count then stays persons.
+    pub(crate) fn request_pieces(&mut self, pieces: usize, unit: &str) -> Result<(), Issue> {
+        let batch = match &self.batch {
+            Some(batch) if batch.unit.eq_ignore_ascii_case(unit) => batch,
+            _ => {
+                return Err(Issue::UnknownBatchUnit {
+                    dish: self.name.clone(),
+                    unit: unit.to_string(),
+                })
+            }
+        };
+        self.people = Some((pieces as f32 / batch.per_person).round() as usize);
+        self.pieces = Some(pieces);
+        Ok(())
+    }
 
+    /// Whole recipes needed for the requested pieces or persons, for batch dishes.
+    pub(crate) fn batches(&self) -> Option<usize> {
+        let batch = self.batch.as_ref()?;
+        let pieces = match self.pieces {
+            Some(pieces) => pieces as f32,
+            None => self.people.unwrap_or(self.recepie_people) as f32 * batch.per_person,
+        };
+        let pieces = pieces * (1.0 + self.surplus.unwrap_or(0.0));
+        Some((pieces / batch.size as f32 - 1e-4).ceil().max(0.0) as usize)
+    }
+
+    /// Portions cooked on top of the head count for a Reste meal.
+    pub(crate) fn leftovers(&self) -> f32 {
+        self.people.unwrap_or(self.recepie_people) as f32 * self.surplus.unwrap_or(0.0)
+    }
+
+    /// Portions cooked, the head count and the planned surplus.
+    pub(crate) fn portions(&self) -> f32 {
+        self.people.unwrap_or(self.recepie_people) as f32 + self.leftovers()
+    }
+
+    /// Ingredients whose name starts with a probable unit, for `--lint-units`.
+    ///
+    /// Advisory only, loading a dish never reports these.
+    pub(crate) fn probable_units(&self, units: &UnitRegistry) -> Vec<Issue> {
         self.ingredients
             .iter()
-            .map(|ing| Ingredient {
-                amount: ing.amount * scale,
+            .zip(&self.lines)
+            .filter_map(|(ingredient, line)| {
+                let unit = units.probable_unit(&ingredient.name)?;
+                Some(Issue::ProbableUnit {
+                    dish: self.name.clone(),
+                    ingredient: ingredient.name.clone(),
+                    unit: unit.to_string(),
+                    at: Position {
+                        line: *line,
+                        column: 1,
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Factor from the recipe to the cooked amount.
+    fn scale(&self) -> f32 {
+        match self.batches() {
+            Some(batches) => batches as f32,
+            None => self.portions() / self.recepie_people.max(1) as f32,
+        }
+    }
+
+    /// Recalculate the amounts of quantity ranges like "2-3 EL".
+    pub(crate) fn resolve_ranges(&mut self, policy: RangePolicy) {
+        for ingredient in &mut self.ingredients {
+            if let Some(quantity) = &ingredient.quantity {
+                ingredient.amount = quantity.value(policy);
+            }
+        }
+    }
+
+    /// Resolve ingredients referencing other dishes, e.g. `500 ml [[Bratensoße]]`.
+    ///
+    /// The referenced dish is scaled to the requested amount and its
+    /// ingredients replace the reference on the shopping list, attributed to
+    /// both dishes. Missing dishes and reference cycles are reported.
+    pub(crate) fn expand_sub_dishes(&mut self, cookbook: &CookBook) {
+        let mut chain = vec![self.name.clone()];
+        self.expand(cookbook, &mut chain);
+    }
+
+    fn expand(&mut self, cookbook: &CookBook, chain: &mut Vec<String>) {
+        let mut components = HashMap::new();
+        let mut issues = Vec::new();
+
+        for (i, ingredient) in self.ingredients.iter().enumerate() {
+            let Some(name) = sub_dish_name(&ingredient.name) else {
+                continue;
+            };
+            if chain.iter().any(|dish| dish == name) {
+                let mut cycle = chain.clone();
+                cycle.push(name.to_string());
+                issues.push(Issue::SubDishCycle { chain: cycle });
+                continue;
+            }
+            let Some(path) = cookbook.get(name) else {
+                issues.push(Issue::MissingDish {
+                    dish: name.to_string(),
+                });
+                continue;
+            };
+            let mut sub = match Dish::from_file_with_units(path, name, 1, &cookbook.units) {
+                Ok(sub) => sub,
+                Err(e) => {
+                    issues.push(Issue::DishParse {
+                        dish: name.to_string(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
+            sub.resolve_ranges(cookbook.ranges);
+            chain.push(name.to_string());
+            sub.expand(cookbook, chain);
+            chain.pop();
+            issues.append(&mut sub.issues);
+            for (name, rule) in sub.scaling.drain() {
+                self.scaling.entry(name).or_insert(rule);
+            }
+
+            let factor = match sub.factor_for(ingredient.amount, &ingredient.measure) {
+                Some(factor) => factor,
+                None => {
+                    issues.push(Issue::UnknownYield {
+                        dish: name.to_string(),
+                        measure: ingredient.measure.clone(),
+                    });
+                    1.0
+                }
+            };
+            let parts = sub
+                .base_ingredients()
+                .into_iter()
+                .map(|part| Ingredient {
+                    amount: part.amount * factor,
+                    quantity: scaled(&part, factor),
+                    dish: format!("{} ← {}", part.dish, self.name),
+                    ..part
+                })
+                .collect();
+            components.insert(i, parts);
+        }
+
+        self.components = components;
+        self.issues.append(&mut issues);
+    }
+
+    /// Fill in scaling rules for ingredients without an annotation.
+    pub(crate) fn resolve_scaling(&mut self, rules: &ScalingRules, aliases: &Aliases) {
+        for ingredient in self.base_ingredients() {
+            if self.scaling.contains_key(&ingredient.name) {
+                continue;
+            }
+            if let Some(rule) = rules.get(&ingredient.name, aliases) {
+                self.scaling.insert(ingredient.name, rule);
+            }
+        }
+    }
+
+    /// Share of the recipe needed for an amount of this dish.
+    ///
+    /// Amounts without a measure are portions. Others are compared to the
+    /// declared yield, or to the sum of all ingredients of the same unit family.
+    fn factor_for(&self, amount: f32, measure: &str) -> Option<f32> {
+        if measure.is_empty() {
+            return Some(amount / self.recepie_people.max(1) as f32);
+        }
+        let total = match &self.yields {
+            Some((yields, unit)) => convert(*yields, unit, measure)?,
+            None => self
+                .base_ingredients()
+                .iter()
+                .filter(|ingredient| !ingredient.measure.is_empty())
+                .filter_map(|ingredient| convert(ingredient.amount, &ingredient.measure, measure))
+                .sum(),
+        };
+        (total > 0.0).then(|| amount / total)
+    }
+
+    /// Recipe ingredients with resolved sub-dishes expanded, unscaled.
+    pub(crate) fn base_ingredients(&self) -> Vec<Ingredient> {
+        let mut ingredients = Vec::new();
+        for (i, ingredient) in self.ingredients.iter().enumerate() {
+            match self.components.get(&i) {
+                Some(parts) => ingredients.extend(parts.iter().cloned()),
+                None => ingredients.push(ingredient.clone()),
+            }
+        }
+        ingredients
+    }
+
+    pub(crate) fn shopping_list(&self) -> Vec<Ingredient> {
+        self.base_ingredients()
+            .iter()
+            .flat_map(|ingredient| self.scaled_items(ingredient))
+            .collect()
+    }
+
+    /// A recipe ingredient scaled to the dish's people, split by vegan share.
+    fn scaled_items(&self, ing: &Ingredient) -> Vec<Ingredient> {
+        let target = self.people.unwrap_or(self.recepie_people) as f32;
+        let scale = self.scale();
+
+        let vegan_share = match &self.vegan {
+            Some(vegan) if target > 0.0 => (vegan.people as f32 / target).min(1.0),
+            _ => 0.0,
+        };
+
+        let mut items = Vec::new();
+        let rule = self.scaling.get(&ing.name).copied().unwrap_or_default();
+        let amount = rule.apply(ing.amount, scale);
+        // Quantities follow the rule through the effective factor.
+        let scale = if ing.amount > 0.0 {
+            amount / ing.amount
+        } else {
+            scale
+        };
+        let substitute = self
+            .vegan
+            .as_ref()
+            .and_then(|vegan| vegan.substitutes.get(&ing.name));
+
+        match substitute {
+            Some(substitute) if vegan_share > 0.0 => {
+                if vegan_share < 1.0 {
+                    items.push(Ingredient {
+                        amount: amount * (1.0 - vegan_share),
+                        measure: ing.measure.clone(),
+                        name: ing.name.clone(),
+                        dish: ing.dish.clone(),
+                        packed: None,
+                        quantity: scaled(ing, scale * (1.0 - vegan_share)),
+                    });
+                }
+                items.push(Ingredient {
+                    amount: amount * vegan_share,
+                    measure: ing.measure.clone(),
+                    name: substitute.clone(),
+                    dish: format!("{} (vegan)", ing.dish),
+                    packed: None,
+                    quantity: scaled(ing, scale * vegan_share),
+                });
+            }
+            _ => items.push(Ingredient {
+                amount,
                 measure: ing.measure.clone(),
                 name: ing.name.clone(),
                 dish: ing.dish.clone(),
-            })
-            .collect()
+                packed: None,
+                quantity: scaled(ing, scale),
+            }),
+        }
+        items
     }
 
     /// Generate markdown for the dish with scaled quantities.
     pub(crate) fn as_markdown(&self) -> String {
         let target_people = self.people.unwrap_or(self.recepie_people);
-        println!("{target_people}");
-        let scaled_ingredients = self.shopping_list();
+
+        let mut serving = match (&self.batch, self.pieces) {
+            (Some(batch), Some(pieces)) => format!("{} {}", pieces, batch.unit),
+            _ => format!("{} Personen", target_people),
+        };
+        if let Some(vegan) = &self.vegan {
+            serving.push_str(&format!(", davon {} vegan", vegan.people));
+        }
+        if let Some(surplus) = self.surplus {
+            serving.push_str(&format!(", +{:.0} % für Reste", surplus * 100.0));
+        }
+        if let (Some(batch), Some(batches)) = (&self.batch, self.batches()) {
+            serving.push_str(&format!(", {} × {} {}", batches, batch.size, batch.unit));
+        }
 
         let mut output = String::new();
-        output.push_str(&format!(
-            "## {} ({} Personen)\n\n",
-            self.name, target_people
-        ));
+        output.push_str(&format!("## {} ({})\n\n", self.name, serving));
         output.push_str("### Zutaten\n");
 
-        for ingredient in scaled_ingredients {
-            let amount_str = if ingredient.measure.is_empty() {
-                format!("{:.1}", ingredient.amount)
-            } else {
-                format!("{:.1} {}", ingredient.amount, ingredient.measure)
-            };
-            output.push_str(&format!("- {} {}\n", amount_str, ingredient.name));
+        for (i, ingredient) in self.ingredients.iter().enumerate() {
+            for item in self.scaled_items(ingredient) {
+                output.push_str(&format!("- {}\n", md_ingredient(&item)));
+            }
+            // Ingredients of sub-dishes are listed below their reference.
+            for part in self.components.get(&i).into_iter().flatten() {
+                for item in self.scaled_items(part) {
+                    output.push_str(&format!("  - {}\n", md_ingredient(&item)));
+                }
+            }
+        }
+
+        for gap in &self.gaps {
+            output.push_str(&format!(
+                "- **Nicht gelesen (Zeile {}):** `{}`\n",
+                gap.line, gap.text
+            ));
+        }
+
+        if let Some(nutrition) = &self.nutrition {
+            output.push('\n');
+            output.push_str(&nutrition.as_markdown());
+            output.push('\n');
+        }
+
+        if let Some(labels) = &self.labels {
+            output.push('\n');
+            output.push_str(&labels.as_markdown());
+            output.push('\n');
+        }
+
+        if let Some(costs) = &self.costs {
+            let per_person = costs.total() / target_people.max(1) as f32;
+            output.push('\n');
+            output.push_str(&format!("**Kosten pro Person:** {}", euro(per_person)));
+            let missing = costs.missing();
+            if !missing.is_empty() {
+                output.push_str(&format!(" (ohne {})", missing.join(", ")));
+            }
+            output.push('\n');
         }
 
         // Add preparation section if it exists
         if !self.blocks.is_empty() {
             output.push('\n');
-            output.push_str("#");
+            output.push('#');
             for block in &self.blocks {
                 output.push_str(block);
                 output.push('\n');
@@ -145,52 +588,216 @@ impl Dish {
 fn parse_ingredients_section(
     node: &tree_sitter::Node,
     content: &str,
+    units: &UnitRegistry,
     dish_name: &str,
-    ingredients: &mut Vec<Ingredient>,
-) {
+    scaling: &mut HashMap<String, Scaling>,
+    issues: &mut Vec<Issue>,
+) -> Vec<(usize, Ingredient)> {
+    let mut ingredients = Vec::new();
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
         if child.kind() == "ingredient_line" {
-            if let Some(ingredient) = parse_ingredient_node(&child, content, dish_name) {
-                ingredients.push(ingredient);
+            match parse_ingredient_node(&child, content, units, dish_name) {
+                Ok(Some((ingredient, annotation))) => {
+                    if let Some(rule) = annotation {
+                        match Scaling::parse(&rule) {
+                            Some(rule) => {
+                                scaling.insert(ingredient.name.clone(), rule);
+                            }
+                            None => issues.push(Issue::UnknownScaling {
+                                dish: dish_name.to_string(),
+                                ingredient: ingredient.name.clone(),
+                                rule,
+                            }),
+                        }
+                    }
+                    ingredients.push((child.start_position().row + 1, ingredient));
+                }
+                Ok(None) => {}
+                Err(issue) => issues.push(issue),
+            }
+        }
+    }
+
+    ingredients
+}
+
+/// Lines covered by `ERROR` or `MISSING` nodes of the syntax tree, and whether
+/// they are part of the ingredients section.
+///
+/// Every line is reported once, with its text taken from `source`. Lines
+/// with an ingredient that was read despite stray tokens are left out.
+fn unreadable_lines(root: tree_sitter::Node, source: &str) -> Vec<(Gap, bool)> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut rows = Vec::new();
+    let mut read = Vec::new();
+    let mut stack = vec![(root, false)];
+
+    while let Some((node, in_ingredients)) = stack.pop() {
+        let in_ingredients = in_ingredients || node.kind() == "ingredients_section";
+        if node.is_error() || node.is_missing() {
+            let (start, mut end) = (node.start_position().row, node.end_position().row);
+            if end > start && node.end_position().column == 0 {
+                end -= 1;
             }
+            for row in start..=end {
+                if !rows.iter().any(|(seen, _)| *seen == row) {
+                    rows.push((row, in_ingredients));
+                }
+            }
+            continue;
+        }
+        let name = node
+            .child_by_field_name("name")
+            .filter(|name| !name.is_missing() && !name.byte_range().is_empty());
+        if let Some(name) = name {
+            read.push(name.start_position().row);
+        }
+        if !node.has_error() {
+            continue;
         }
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.children(&mut cursor).collect();
+        stack.extend(
+            children
+                .into_iter()
+                .rev()
+                .map(|child| (child, in_ingredients)),
+        );
     }
+
+    rows.sort();
+    rows.into_iter()
+        .filter(|(row, _)| !read.contains(row))
+        .filter_map(|(row, in_ingredients)| {
+            let text = lines.get(row)?.trim();
+            let text = text.strip_prefix('-').unwrap_or(text).trim();
+            (!text.is_empty()).then(|| {
+                let gap = Gap {
+                    line: row + 1,
+                    text: text.to_string(),
+                };
+                (gap, in_ingredients)
+            })
+        })
+        .collect()
 }
 
+/// Parse a single ingredient line.
+///
+/// Lines without a name are skipped, lines with an unreadable quantity are
+/// reported as an [`Issue`]. The unit after a quantity is looked up in
+/// `units`, the grammar's unit token is only used to find where the text
+/// starts. A scaling annotation like `{fix}` is split off the name and
+/// returned as written.
 fn parse_ingredient_node(
     node: &tree_sitter::Node,
     content: &str,
+    units: &UnitRegistry,
     dish_name: &str,
-) -> Option<Ingredient> {
-    let name_node = node.child_by_field_name("name")?;
-    let name = content[name_node.byte_range()].trim().to_string();
-
-    let amount = if let Some(quantity_node) = node.child_by_field_name("quantity") {
-        let quantity_str = content[quantity_node.byte_range()].trim();
-        quantity_str.parse::<f32>().ok()?
-    } else {
-        1.0
+) -> Result<Option<(Ingredient, Option<String>)>, Issue> {
+    let Some(name_node) = node
+        .child_by_field_name("name")
+        .filter(|name_node| !name_node.is_missing())
+    else {
+        return Ok(None);
+    };
+    let quantity_node = node.child_by_field_name("quantity");
+    let (unit, text) = match quantity_node {
+        Some(_) => {
+            let start = node
+                .child_by_field_name("unit")
+                .map_or(name_node.start_byte(), |unit_node| unit_node.start_byte());
+            units.split(&content[start..name_node.end_byte()])
+        }
+        None => ("", content[name_node.byte_range()].trim()),
     };
+    let (name, annotation) = split_annotation(text);
+    if name.is_empty() {
+        return Ok(None);
+    }
+    let (name, annotation) = (name.to_string(), annotation.map(str::to_string));
 
-    let unit = if let Some(unit_node) = node.child_by_field_name("unit") {
-        content[unit_node.byte_range()].trim().to_string()
-    } else {
-        String::new()
+    let quantity = quantity_node
+        .map(|quantity_node| {
+            let quantity_str = content[quantity_node.byte_range()].trim();
+            Quantity::parse(quantity_str).ok_or_else(|| quantity_str.to_string())
+        })
+        .transpose()
+        .map_err(|quantity| Issue::UnparseableQuantity {
+            dish: dish_name.to_string(),
+            ingredient: name.clone(),
+            quantity,
+            at: quantity_node
+                .map_or(node.start_position(), |quantity_node| {
+                    quantity_node.start_position()
+                })
+                .into(),
+        })?;
+
+    Ok(Some((
+        Ingredient {
+            amount: quantity
+                .as_ref()
+                .map_or(1.0, |quantity| quantity.value(RangePolicy::default())),
+            measure: unit.to_string(),
+            name,
+            dish: dish_name.to_string(),
+            packed: None,
+            quantity,
+        },
+        annotation,
+    )))
+}
+
+/// Recipe line of a scaled ingredient, without the list marker.
+fn md_ingredient(ingredient: &Ingredient) -> String {
+    let amount = match &ingredient.quantity {
+        Some(quantity) => quantity.render(),
+        None => format!("{:.1}", ingredient.amount),
     };
+    if ingredient.measure.is_empty() {
+        format!("{} {}", amount, ingredient.name)
+    } else {
+        format!("{} {} {}", amount, ingredient.measure, ingredient.name)
+    }
+}
+
+/// Name of the dish referenced by an ingredient like `[[Bratensoße]]`.
+fn sub_dish_name(name: &str) -> Option<&str> {
+    name.strip_prefix("[[")?.strip_suffix("]]").map(str::trim)
+}
+
+/// Yield declared in the preamble, e.g. `Ergibt: 1,5 l`.
+fn parse_yield(line: &str) -> Option<(f32, String)> {
+    let text = line.trim().strip_prefix("Ergibt:")?;
+    let (quantity, measure) = Quantity::parse_prefix(text)?;
+    Some((
+        quantity.value(RangePolicy::default()),
+        measure.trim().to_string(),
+    ))
+}
+
+/// Pieces a person eats, declared in the preamble as `Pro Person: 2`.
+fn parse_per_person(line: &str) -> Option<f32> {
+    let text = line.trim().strip_prefix("Pro Person:")?;
+    let (quantity, _) = Quantity::parse_prefix(text)?;
+    Some(quantity.value(RangePolicy::default())).filter(|pieces| *pieces > 0.0)
+}
 
-    Some(Ingredient {
-        amount,
-        measure: unit,
-        name,
-        dish: dish_name.to_string(),
-    })
+/// Scaled recipe quantity of an ingredient.
+fn scaled(ingredient: &Ingredient, factor: f32) -> Option<Quantity> {
+    ingredient
+        .quantity
+        .as_ref()
+        .map(|quantity| quantity.scaled(factor))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::IngredientList;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -297,6 +904,98 @@ mod tests {
         assert_eq!(dish.ingredients[3].name, "Sesamöl");
     }
 
+    #[test]
+    fn test_parse_comma_fraction_and_range_quantities() {
+        let content = r#"2 Personen
+
+## Zutaten
+- 2,5 kg Kartoffeln
+- ½ TL Salz
+- 1/4 l Milch
+- 1 ½ EL Zucker
+- 2-3 EL Öl
+- 1 1/2 Zwiebeln
+- 2–3 Eier
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let file = create_test_dish_file(content);
+        let mut dish = Dish::from_file(file.path(), "Test Dish", 2).unwrap();
+
+        assert!(dish.issues.is_empty());
+        let amounts: Vec<(f32, &str, &str)> = dish
+            .ingredients
+            .iter()
+            .map(|i| (i.amount, i.measure.as_str(), i.name.as_str()))
+            .collect();
+        assert_eq!(
+            amounts,
+            vec![
+                (2.5, "kg", "Kartoffeln"),
+                (0.5, "TL", "Salz"),
+                (0.25, "l", "Milch"),
+                (1.5, "EL", "Zucker"),
+                (2.5, "EL", "Öl"),
+                (1.5, "", "Zwiebeln"),
+                (2.5, "", "Eier"),
+            ]
+        );
+
+        // Unscaled recipes keep the quantities as written.
+        let markdown = dish.as_markdown();
+        assert!(markdown.contains("- 2,5 kg Kartoffeln"));
+        assert!(markdown.contains("- ½ TL Salz"));
+        assert!(markdown.contains("- 1 ½ EL Zucker"));
+        assert!(markdown.contains("- 2-3 EL Öl"));
+        assert!(markdown.contains("- 1 1/2 Zwiebeln"));
+        assert!(markdown.contains("- 2–3 Eier"));
+
+        dish.resolve_ranges(RangePolicy::Upper);
+        assert_eq!(dish.ingredients[4].amount, 3.0);
+
+        dish.people = Some(4);
+        let markdown = dish.as_markdown();
+        assert!(markdown.contains("- 5,0 kg Kartoffeln"));
+        assert!(markdown.contains("- 4.0–6.0 EL Öl"));
+    }
+
+    #[test]
+    fn test_parse_unparseable_quantity_is_reported() {
+        let content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+- 1/0 l Milch
+- 3-2 EL Öl
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let file = create_test_dish_file(content);
+        let dish = Dish::from_file(file.path(), "Test Dish", 2).unwrap();
+
+        assert_eq!(dish.ingredients.len(), 1);
+        assert_eq!(dish.issues.len(), 2);
+        match &dish.issues[0] {
+            Issue::UnparseableQuantity {
+                ingredient,
+                quantity,
+                at,
+                ..
+            } => {
+                assert_eq!(ingredient, "Milch");
+                assert_eq!(quantity, "1/0");
+                assert_eq!((at.line, at.column), (5, 3));
+            }
+            other => panic!("unexpected issue: {other:?}"),
+        }
+        match &dish.issues[1] {
+            Issue::UnparseableQuantity { quantity, .. } => assert_eq!(quantity, "3-2"),
+            other => panic!("unexpected issue: {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_invalid_format_fails() {
         let content = r#"This is not a valid dish file
@@ -309,6 +1008,120 @@ Just some random text
         assert!(result.unwrap_err().to_string().contains("Parse error"));
     }
 
+    #[test]
+    fn test_parse_recovers_from_syntax_errors() {
+        let content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+## Tipp
+- 2,5
+- 3 Eier
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let file = create_test_dish_file(content);
+        let dish = Dish::from_file(file.path(), "Test Dish", 2).unwrap();
+
+        let names: Vec<&str> = dish.ingredients.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, ["Butter", "Eier"]);
+        assert_eq!(
+            dish.gaps,
+            [
+                Gap {
+                    line: 5,
+                    text: "## Tipp".to_string(),
+                },
+                Gap {
+                    line: 6,
+                    text: "2,5".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            dish.issues[0].to_string(),
+            "dish [[Test Dish]] line 5: cannot read '## Tipp', leaving it out"
+        );
+        assert!(dish
+            .as_markdown()
+            .contains("- **Nicht gelesen (Zeile 6):** `2,5`\n"));
+    }
+
+    #[test]
+    fn test_unit_spellings_accumulate_to_one_line() {
+        let content = r#"2 Personen
+
+## Zutaten
+- 1 Zehe Knoblauch
+- 2 Zehen Knoblauch
+- 1 Esslöffel Öl
+- 3 TL Öl
+"#;
+        let file = create_test_dish_file(content);
+        let dish = Dish::from_file(file.path(), "Test Dish", 2).unwrap();
+
+        let mut list = IngredientList::from(dish.ingredients);
+        list.accumulate();
+
+        assert_eq!(list.0.len(), 2);
+        let garlic = list.0.iter().find(|i| i.name == "Knoblauch").unwrap();
+        assert_eq!((garlic.amount, garlic.measure.as_str()), (3.0, "Zehe"));
+        let oil = list.0.iter().find(|i| i.name == "Öl").unwrap();
+        assert_eq!((oil.amount, oil.measure.as_str()), (2.0, "EL"));
+    }
+
+    #[test]
+    fn test_parse_units_from_registry() {
+        let content = r#"2 Personen
+
+## Zutaten
+- 1 Lorbeerblatt
+- 100 gemahlene Mandeln
+- 2 Zehen Knoblauch
+- 1 Pck. Vanillezucker
+- 2 Knollen Sellerie
+- 1 Pfd. Kartoffeln
+- Prise Salz
+- 50 g Butter
+"#;
+        let file = create_test_dish_file(content);
+        let units = UnitRegistry::parse("| Knolle | Knollen | |\n");
+        let dish = Dish::from_file_with_units(file.path(), "Test Dish", 2, &units).unwrap();
+
+        let parsed: Vec<(&str, &str)> = dish
+            .ingredients
+            .iter()
+            .map(|i| (i.measure.as_str(), i.name.as_str()))
+            .collect();
+        assert_eq!(
+            parsed,
+            [
+                ("", "Lorbeerblatt"),
+                ("", "gemahlene Mandeln"),
+                ("Zehe", "Knoblauch"),
+                ("Packung", "Vanillezucker"),
+                ("Knolle", "Sellerie"),
+                ("", "Pfd. Kartoffeln"),
+                ("", "Prise Salz"),
+                ("g", "Butter"),
+            ]
+        );
+        assert!(dish.issues.is_empty());
+        let issues: Vec<String> = dish
+            .probable_units(&units)
+            .iter()
+            .map(|i| i.to_string())
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                "dish [[Test Dish]] line 9: 'Pfd. Kartoffeln' starts with probable unit 'Pfd.'",
+                "dish [[Test Dish]] line 10: 'Prise Salz' starts with probable unit 'Prise'",
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_missing_persons_line() {
         let content = r#"
@@ -373,6 +1186,20 @@ Just some random text
         assert_eq!(items[2].name, "Eier");
     }
 
+    #[test]
+    fn test_shopping_list_includes_surplus() {
+        let content = "2 Personen\n\n## Zutaten\n- 100 g Butter\n";
+        let file = create_test_dish_file(content);
+        let mut dish = Dish::from_file(file.path(), "Test Dish", 10).unwrap();
+        dish.surplus = Some(0.2);
+
+        assert!((dish.leftovers() - 2.0).abs() < 1e-6);
+        assert!((dish.shopping_list()[0].amount - 600.0).abs() < 1e-3);
+        assert!(dish
+            .as_markdown()
+            .starts_with("## Test Dish (10 Personen, +20 % für Reste)"));
+    }
+
     #[test]
     fn test_shopping_list_scales_down() {
         let content = r#"2 Personen
@@ -511,7 +1338,127 @@ Just some random text
         let markdown = dish.as_markdown();
 
         assert!(markdown.contains("## Simple Dish (2 Personen)"));
-        assert!(markdown.contains("- 100.0 g Butter"));
-        assert!(markdown.contains("- 2.0 Eggs"));
+        assert!(markdown.contains("- 100 g Butter"));
+        assert!(markdown.contains("- 2 Eggs"));
+    }
+
+    #[test]
+    fn test_shopping_list_splits_vegan_portions() {
+        let content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+- 200 g Mehl
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let file = create_test_dish_file(content);
+        let mut dish = Dish::from_file(file.path(), "Kuchen", 8).unwrap();
+        dish.vegan = Some(VeganPortions {
+            people: 2,
+            substitutes: HashMap::from([("Butter".to_string(), "Vegane Butter".to_string())]),
+        });
+
+        let items = dish.shopping_list();
+        assert_eq!(items.len(), 3);
+
+        assert_eq!(items[0].name, "Butter");
+        assert!((items[0].amount - 300.0).abs() < 1e-4);
+        assert_eq!(items[0].dish, "Kuchen");
+
+        assert_eq!(items[1].name, "Vegane Butter");
+        assert!((items[1].amount - 100.0).abs() < 1e-4);
+        assert_eq!(items[1].dish, "Kuchen (vegan)");
+
+        assert_eq!(items[2].name, "Mehl");
+        assert!((items[2].amount - 800.0).abs() < 1e-4);
+
+        let markdown = dish.as_markdown();
+        assert!(markdown.contains("## Kuchen (8 Personen, davon 2 vegan)"));
+        assert!(markdown.contains("- 100 g Vegane Butter"));
+    }
+
+    #[test]
+    fn test_scaling_annotations_and_rules() {
+        let content = r#"4 Personen
+
+## Zutaten
+- 1 Stück Lorbeerblatt {fix}
+- 1 TL Salz {sublinear 0,5}
+- 3 Eier
+- 1 Prise Pfeffer
+- 2 Zwiebeln {quadratisch}
+- 500 g Linsen
+
+## Zubereitung
+1. Kochen.
+"#;
+        let file = create_test_dish_file(content);
+        let mut dish = Dish::from_file(file.path(), "Linseneintopf", 18).unwrap();
+
+        assert_eq!(dish.ingredients[0].name, "Lorbeerblatt");
+        assert_eq!(dish.scaling["Lorbeerblatt"], Scaling::Fixed);
+        assert_eq!(dish.issues.len(), 1);
+        assert_eq!(
+            dish.issues[0].to_string(),
+            "dish [[Linseneintopf]] has unknown scaling 'quadratisch' for Zwiebeln, scaling linearly"
+        );
+
+        let (rules, _) = ScalingRules::parse("Pfeffer: fix\n");
+        dish.resolve_scaling(&rules, &Aliases::parse("Ei: Eier\n"));
+
+        let items = dish.shopping_list();
+        assert_eq!(items[0].amount, 1.0);
+        assert!((items[1].amount - 4.5f32.sqrt()).abs() < 1e-4);
+        assert_eq!(items[2].amount, 14.0);
+        assert_eq!(items[3].amount, 1.0);
+        assert_eq!(items[4].amount, 9.0);
+        assert_eq!(items[5].amount, 2250.0);
+
+        let markdown = dish.as_markdown();
+        assert!(markdown.contains("- 1 Stück Lorbeerblatt\n"));
+        assert!(markdown.contains("- 2.1 TL Salz\n"));
+        assert!(markdown.contains("- 14.0 Eier\n"));
+        assert!(markdown.contains("- 2250.0 g Linsen\n"));
+    }
+
+    #[test]
+    fn test_batch_yield_rounds_up_to_whole_batches() {
+        let content = r#"1 Blech
+Pro Person: 0,125
+
+## Zutaten
+- 500 g Mehl
+- 1 kg Äpfel
+
+## Zubereitung
+1. Backen.
+"#;
+        let file = create_test_dish_file(content);
+        let mut dish = Dish::from_file(file.path(), "Apfelkuchen", 20).unwrap();
+
+        assert_eq!(
+            dish.batch,
+            Some(Batch {
+                size: 1,
+                unit: "Blech".to_string(),
+                per_person: 0.125,
+            })
+        );
+        assert_eq!(dish.recepie_people, 8);
+        assert_eq!(dish.batches(), Some(3));
+        assert_eq!(dish.shopping_list()[0].amount, 1500.0);
+        assert!(dish
+            .as_markdown()
+            .contains("## Apfelkuchen (20 Personen, 3 × 1 Blech)"));
+
+        dish.request_pieces(2, "blech").unwrap();
+        assert_eq!(dish.people, Some(16));
+        assert_eq!(dish.shopping_list()[1].amount, 2.0);
+        assert!(dish
+            .as_markdown()
+            .contains("## Apfelkuchen (2 Blech, 2 × 1 Blech)"));
+        assert!(dish.request_pieces(2, "Springform").is_err());
     }
 }
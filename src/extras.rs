@@ -0,0 +1,279 @@
+use std::{fs, path::PathBuf, str::FromStr, sync::LazyLock};
+
+use anyhow::{anyhow, Context, Result};
+use markdown::{mdast::Node, ParseOptions};
+use regex::Regex;
+
+use crate::{quantity::Quantity, types::Ingredient, units::Unit};
+
+/// Count words and their canonical measure, matched case-insensitively.
+const MEASURES: &[(&[&str], &str)] = &[
+    (
+        &["packung", "packungen", "pck.", "pck", "pkg.", "pkg", "pack"],
+        "Packung",
+    ),
+    (&["dose", "dosen"], "Dose"),
+    (&["glas", "gläser"], "Glas"),
+    (&["flasche", "flaschen"], "Flasche"),
+    (&["tafel", "tafeln"], "Tafel"),
+    (&["tube", "tuben"], "Tube"),
+    (&["bund"], "Bund"),
+    (&["becher"], "Becher"),
+    (&["stück", "stk.", "stk"], "Stück"),
+];
+
+/// Counts like "3x O-Saft" or "3 × Sekt".
+static COUNT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d+(?:[.,]\d+)?)\s*[x×]\s+(.+)$").unwrap());
+
+/// A number glued to its unit, like "800g Pilze".
+static GLUED: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d+(?:[.,]\d+)?)([[:alpha:]]+)\s+(.+)$").unwrap());
+
+/// An extra list given on the command line as `FILE` or `FILE=Einkauf`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ExtraSource {
+    pub(crate) path: PathBuf,
+    /// Label of the Einkauf the items go to, the first one if not given.
+    pub(crate) trip: Option<String>,
+}
+
+impl FromStr for ExtraSource {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (path, trip) = match value.split_once('=') {
+            Some((path, trip)) => (path, Some(trip.trim().to_string())),
+            None => (value, None),
+        };
+        if path.trim().is_empty() {
+            return Err(format!("missing file in '{value}'"));
+        }
+        Ok(Self {
+            path: PathBuf::from(path.trim()),
+            trip: trip.filter(|trip| !trip.is_empty()),
+        })
+    }
+}
+
+/// Items of a hand-written shopping list.
+#[derive(Debug, Default)]
+pub(crate) struct Extras {
+    /// Items with a readable quantity, attributed to the list's name.
+    pub(crate) items: Vec<Ingredient>,
+    /// Lines without one, kept as written.
+    pub(crate) verbatim: Vec<String>,
+}
+
+impl Extras {
+    pub(crate) fn from_file(source: &ExtraSource) -> Result<Self> {
+        let path = &source.path;
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read extra list: {}", path.display()))?;
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("Extras");
+        Self::parse(&content, name)
+    }
+
+    /// Read the list items of a markdown file, headings and other text are ignored.
+    ///
+    /// Checked items are kept verbatim, as they are already taken care of.
+    pub(crate) fn parse(content: &str, name: &str) -> Result<Self> {
+        let root = markdown::to_mdast(content, &ParseOptions::gfm())
+            .map_err(|e| anyhow!("Failed to parse extra list: {e}"))?;
+        let mut extras = Self::default();
+        extras.collect(&root, content, name);
+        Ok(extras)
+    }
+
+    fn collect(&mut self, node: &Node, content: &str, name: &str) {
+        if let Node::ListItem(item) = node {
+            let line = item
+                .position
+                .as_ref()
+                .map(|position| &content[position.start.offset..position.end.offset])
+                .and_then(|source| source.lines().next())
+                .unwrap_or_default()
+                .trim();
+            let text = item
+                .children
+                .first()
+                .and_then(Node::position)
+                .map(|position| content[position.start.offset..position.end.offset].trim())
+                .unwrap_or_default();
+
+            match parse_item(text).filter(|_| item.checked != Some(true)) {
+                Some((amount, measure, item_name, dish)) => self.items.push(Ingredient {
+                    amount,
+                    measure,
+                    name: item_name,
+                    dish: dish.unwrap_or_else(|| name.to_string()),
+                    packed: None,
+                    quantity: None,
+                }),
+                None if !line.is_empty() => self.verbatim.push(line.to_string()),
+                None => {}
+            }
+        }
+
+        for child in node.children().into_iter().flatten() {
+            self.collect(child, content, name);
+        }
+    }
+}
+
+/// Amount, measure, name and dish of a free-text item.
+type Item = (f32, String, String, Option<String>);
+
+/// Read a free-text item.
+///
+/// Understands "3x O-Saft", "2,5 kg Couscous", "800g Pilze",
+/// "Je 1 Packung Gummibärchen" and generated items like "Eier: 60.0 (Kuchen)".
+fn parse_item(text: &str) -> Option<Item> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("Je ")
+        .or_else(|| text.strip_prefix("je "))
+        .unwrap_or(text);
+
+    if let Some((name, rest)) = text.split_once(": ") {
+        let (quantity, dish) = match rest.trim().strip_suffix(')') {
+            Some(rest) => match rest.rsplit_once(" (") {
+                Some((quantity, dish)) => (quantity, Some(dish.to_string())),
+                None => (rest, None),
+            },
+            None => (rest, None),
+        };
+        let (amount, rest) = Quantity::parse_prefix(quantity)?;
+        let rest = rest.trim();
+        if !rest.is_empty() && measure(rest).is_none() {
+            return None;
+        }
+        let measure = measure(rest).unwrap_or_default();
+        return Some((amount.upper, measure, name.trim().to_string(), dish));
+    }
+
+    let (amount, rest) = if let Some(captures) = COUNT.captures(text) {
+        (number(&captures[1])?, captures.get(2)?.as_str())
+    } else if let Some((quantity, rest)) = Quantity::parse_prefix(text) {
+        (quantity.upper, rest)
+    } else if let Some(captures) = GLUED.captures(text) {
+        let unit = captures.get(2)?.as_str();
+        let rest = captures.get(3)?.as_str();
+        let measure = measure(unit)?;
+        return Some((
+            number(&captures[1])?,
+            measure,
+            rest.trim().to_string(),
+            None,
+        ));
+    } else {
+        return None;
+    };
+
+    let rest = rest.trim();
+    let (measure, name) = match rest.split_once(char::is_whitespace) {
+        Some((word, name)) => match measure(word) {
+            Some(measure) => (measure, name.trim()),
+            None => (String::new(), rest),
+        },
+        None => (String::new(), rest),
+    };
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((amount, measure, name.to_string(), None))
+}
+
+/// The measure a word stands for, units are kept as written.
+fn measure(word: &str) -> Option<String> {
+    let lower = word.to_lowercase();
+    if let Some((_, canonical)) = MEASURES
+        .iter()
+        .find(|(variants, _)| variants.contains(&lower.as_str()))
+    {
+        return Some(canonical.to_string());
+    }
+    Unit::parse(word).map(|_| word.to_string())
+}
+
+fn number(text: &str) -> Option<f32> {
+    text.replace(',', ".").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(text: &str) -> Option<Item> {
+        parse_item(text)
+    }
+
+    fn expected(amount: f32, measure: &str, name: &str) -> Option<Item> {
+        Some((amount, measure.to_string(), name.to_string(), None))
+    }
+
+    #[test]
+    fn test_parse_free_text_items() {
+        assert_eq!(item("3x O-Saft"), expected(3.0, "", "O-Saft"));
+        assert_eq!(item("2,5 kg Couscous"), expected(2.5, "kg", "Couscous"));
+        assert_eq!(
+            item("Je 1 Packung Gummibärchen (vegan) Süß und Sauer"),
+            expected(1.0, "Packung", "Gummibärchen (vegan) Süß und Sauer")
+        );
+        assert_eq!(
+            item("1 Pck. saure vegane Gummibärchen"),
+            expected(1.0, "Packung", "saure vegane Gummibärchen")
+        );
+        assert_eq!(
+            item("1x Bund Lauchzwiebel"),
+            expected(1.0, "Bund", "Lauchzwiebel")
+        );
+        assert_eq!(item("800g Pilze"), expected(800.0, "g", "Pilze"));
+        assert_eq!(item("42 Lollis"), expected(42.0, "", "Lollis"));
+        assert_eq!(
+            item("Eier: 60.0 (Kaiserschmarn)"),
+            Some((
+                60.0,
+                String::new(),
+                "Eier".to_string(),
+                Some("Kaiserschmarn".to_string())
+            ))
+        );
+
+        assert_eq!(item("Bananen, Äpfel, Trauben, Birnen"), None);
+        assert_eq!(item("Viele M&Ms"), None);
+        assert_eq!(item("(Hafer-)Milch Wieviel?"), None);
+        assert_eq!(item("Parmesan"), None);
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let content = "## Frisches\n- [ ] 3x O-Saft  \n- [ ] Bananen, Äpfel\n- [x] 2x Feta\n\n\
+                       Text\n\n## Getränke\n- 10l Wein?\n";
+        let extras = Extras::parse(content, "extrawünsche").unwrap();
+
+        assert_eq!(extras.items.len(), 2);
+        assert_eq!(extras.items[0].name, "O-Saft");
+        assert_eq!(extras.items[0].dish, "extrawünsche");
+        assert_eq!(extras.items[1].measure, "l");
+        assert_eq!(extras.items[1].name, "Wein?");
+        assert_eq!(extras.verbatim, ["- [ ] Bananen, Äpfel", "- [x] 2x Feta"]);
+    }
+
+    #[test]
+    fn test_extra_source() {
+        assert_eq!(
+            "extrawünsche.md=Großmarkt Mi".parse(),
+            Ok(ExtraSource {
+                path: PathBuf::from("extrawünsche.md"),
+                trip: Some("Großmarkt Mi".to_string()),
+            })
+        );
+        assert_eq!("extra.md".parse::<ExtraSource>().unwrap().trip, None);
+        assert!("=Einkauf 1".parse::<ExtraSource>().is_err());
+    }
+}
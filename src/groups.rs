@@ -0,0 +1,179 @@
+use crate::{allergens::Diet, dish::Dish};
+
+/// Participants with a special diet, declared in the plan header as
+/// `Personen: 43 (vegan 12, vegetarisch 8, glutenfrei 2)`.
+///
+/// The groups are disjoint, the remaining participants eat everything.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Groups(pub(crate) Vec<(Diet, usize)>);
+
+impl Groups {
+    /// Read a group list like `vegan 12, vegetarisch 8`.
+    ///
+    /// Returns the groups and the entries that name no known diet.
+    pub(crate) fn parse(list: &str) -> (Self, Vec<String>) {
+        let mut groups = Vec::new();
+        let mut unknown = Vec::new();
+
+        for entry in list.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let group = entry
+                .rsplit_once(char::is_whitespace)
+                .and_then(|(name, count)| {
+                    Some((Diet::parse(name)?, count.trim().parse::<usize>().ok()?))
+                });
+            match group {
+                Some(group) => groups.push(group),
+                None => unknown.push(entry.to_string()),
+            }
+        }
+
+        (Self(groups), unknown)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Head counts of a meal's dishes, `None` for dishes that keep theirs.
+    ///
+    /// Each group eats the first dish of the meal that is tagged with its
+    /// diet, or else one that fits it, e.g. vegetarians a vegan dish. Tagged
+    /// dishes are cooked for their groups only. Untagged dishes are cooked for
+    /// `people` minus the groups that have a dish of their own, unless the
+    /// dish's labels show that it fits them too. Dishes marked as `fixed`,
+    /// e.g. with a count written in the plan, are left alone.
+    pub(crate) fn assign(
+        &self,
+        dishes: &[Dish],
+        fixed: &[bool],
+        people: usize,
+    ) -> Vec<Option<usize>> {
+        let open: Vec<usize> = (0..dishes.len())
+            .filter(|&i| !fixed.get(i).copied().unwrap_or(true))
+            .collect();
+
+        let mut served = vec![0; dishes.len()];
+        let mut alternatives = Vec::new();
+        for &(diet, count) in &self.0 {
+            let tagged = |exact: bool| {
+                open.iter().copied().find(|&i| {
+                    let diets = &dishes[i].diets;
+                    if exact {
+                        diets.contains(&diet)
+                    } else {
+                        diet.fits(diets)
+                    }
+                })
+            };
+            if let Some(i) = tagged(true).or_else(|| tagged(false)) {
+                served[i] += count;
+                alternatives.push((diet, count));
+            }
+        }
+
+        let mut counts = vec![None; dishes.len()];
+        for &i in &open {
+            let dish = &dishes[i];
+            let count = if !dish.diets.is_empty() {
+                match served[i] {
+                    0 => continue,
+                    served => served,
+                }
+            } else {
+                let elsewhere: usize = alternatives
+                    .iter()
+                    .filter(|(diet, _)| !fits(dish, *diet))
+                    .map(|(_, count)| count)
+                    .sum();
+                people.saturating_sub(elsewhere)
+            };
+            if dish.people != Some(count) {
+                counts[i] = Some(count);
+            }
+        }
+
+        counts
+    }
+
+    /// Groups without any dish in the meal that is known to fit them.
+    pub(crate) fn unserved(&self, dishes: &[Dish]) -> Vec<(Diet, usize)> {
+        self.0
+            .iter()
+            .filter(|(diet, _)| !dishes.iter().any(|dish| fits(dish, *diet)))
+            .copied()
+            .collect()
+    }
+}
+
+/// Split a head count like `43 (vegan 12, vegetarisch 8)` into the total and
+/// the group list.
+pub(crate) fn split_persons(text: &str) -> (&str, Option<&str>) {
+    let text = text.trim();
+    match text.strip_suffix(')').and_then(|rest| rest.split_once('(')) {
+        Some((count, groups)) => (count.trim(), Some(groups)),
+        None => (text, None),
+    }
+}
+
+/// Whether a dish is tagged with or labelled as fit for a diet.
+fn fits(dish: &Dish, diet: Diet) -> bool {
+    diet.fits(&dish.diets)
+        || dish
+            .labels
+            .as_ref()
+            .is_some_and(|labels| diet.fits(&labels.diets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    use crate::allergens::Labels;
+
+    fn dish(name: &str, diets: &[Diet], labels: Option<&[Diet]>) -> Dish {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"2 Personen\n\n## Zutaten\n- 100 g Reis\n")
+            .unwrap();
+        let mut dish = Dish::from_file(file.path(), name, 43).unwrap();
+        dish.diets = diets.to_vec();
+        dish.labels = labels.map(|diets| Labels {
+            diets: diets.to_vec(),
+            ..Default::default()
+        });
+        dish
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        assert_eq!(
+            split_persons("43 (vegan 12, vegetarisch 8, glutenfrei 2)"),
+            ("43", Some("vegan 12, vegetarisch 8, glutenfrei 2"))
+        );
+        assert_eq!(split_persons(" 43 "), ("43", None));
+
+        let (groups, unknown) = Groups::parse("vegan 12, Vegetarisch 8, halal 3, glutenfrei");
+        assert_eq!(groups.0, [(Diet::Vegan, 12), (Diet::Vegetarisch, 8)]);
+        assert_eq!(unknown, ["halal 3", "glutenfrei"]);
+    }
+
+    #[test]
+    fn test_assign_head_counts() {
+        let (groups, _) = Groups::parse("vegan 12, vegetarisch 8, glutenfrei 2");
+        let dishes = [
+            dish("Gulasch", &[], None),
+            dish("Gulasch (vegan)", &[Diet::Vegan], None),
+            dish("Obstsalat", &[], Some(&Diet::ALL)),
+            dish("Brot", &[], None),
+        ];
+
+        // Vegans and vegetarians eat the vegan Gulasch, Brot is counted by hand.
+        assert_eq!(
+            groups.assign(&dishes, &[false, false, false, true], 43),
+            [Some(23), Some(20), None, None]
+        );
+        assert_eq!(groups.unserved(&dishes[..2]), [(Diet::Glutenfrei, 2)]);
+        assert!(groups.unserved(&dishes).is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::NaiveDate;
+
+use crate::{diagnostics::Position, dish::Dish};
+
+/// Portions of a dish cooked on top of its head count for a Reste meal.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Surplus {
+    pub(crate) dish: String,
+    /// Day the dish is cooked.
+    pub(crate) date: NaiveDate,
+    pub(crate) portions: f32,
+    /// Position of the meal the dish is cooked for.
+    pub(crate) at: Position,
+}
+
+/// A meal eaten from the surplus of earlier dishes, written as "Reste".
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RestMeal {
+    /// Day and meal, e.g. "Dienstag, 02.04.2026".
+    pub(crate) meal: String,
+    pub(crate) people: usize,
+    /// Portions taken from each earlier dish, oldest first.
+    pub(crate) fed: Vec<Surplus>,
+}
+
+impl RestMeal {
+    /// Leftover portions available for the meal.
+    pub(crate) fn portions(&self) -> f32 {
+        self.fed.iter().map(|surplus| surplus.portions).sum()
+    }
+
+    /// Whether the leftovers feed everyone at the meal.
+    pub(crate) fn covered(&self) -> bool {
+        self.portions() >= self.people as f32 - 1e-3
+    }
+}
+
+/// Surplus waiting for the next Reste meal, in the order it was cooked.
+#[derive(Debug, Default)]
+pub(crate) struct Leftovers {
+    pending: Vec<Surplus>,
+}
+
+impl Leftovers {
+    /// Put the planned surplus of a meal's dishes aside.
+    pub(crate) fn cook(&mut self, dishes: &[Dish], date: NaiveDate, at: Position) {
+        for dish in dishes {
+            let portions = dish.leftovers();
+            if portions > 1e-3 {
+                self.pending.push(Surplus {
+                    dish: dish.name.clone(),
+                    date,
+                    portions,
+                    at,
+                });
+            }
+        }
+    }
+
+    /// Feed a Reste meal, oldest surplus first.
+    ///
+    /// Surplus beyond the head count stays for a later Reste meal.
+    pub(crate) fn serve(&mut self, meal: String, people: usize) -> RestMeal {
+        let mut needed = people as f32;
+        let mut fed = Vec::new();
+
+        for surplus in &mut self.pending {
+            if needed <= 1e-3 {
+                break;
+            }
+            let portions = surplus.portions.min(needed);
+            needed -= portions;
+            surplus.portions -= portions;
+            fed.push(Surplus {
+                portions,
+                ..surplus.clone()
+            });
+        }
+        self.pending.retain(|surplus| surplus.portions > 1e-3);
+
+        RestMeal { meal, people, fed }
+    }
+
+    /// Surplus that no Reste meal eats.
+    pub(crate) fn uneaten(&self) -> &[Surplus] {
+        &self.pending
+    }
+}
+
+/// Table of the Reste meals with the dishes feeding them.
+pub(crate) fn as_markdown(meals: &[RestMeal]) -> String {
+    let mut output = String::from(
+        "| Reste | Personen | Portionen | Gedeckt | Aus |\n| --- | ---: | ---: | --- | --- |\n",
+    );
+    for meal in meals {
+        let sources: Vec<String> = meal
+            .fed
+            .iter()
+            .map(|surplus| {
+                format!(
+                    "{} vom {} ({:.0})",
+                    surplus.dish,
+                    surplus.date.format("%d.%m."),
+                    surplus.portions
+                )
+            })
+            .collect();
+        output.push_str(&format!(
+            "| {} | {} | {:.0} | {} | {} |\n",
+            meal.meal,
+            meal.people,
+            meal.portions(),
+            if meal.covered() { "ja" } else { "nein" },
+            sources.join(", ")
+        ));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surplus(dish: &str, day: u32, portions: f32) -> Surplus {
+        Surplus {
+            dish: dish.to_string(),
+            date: NaiveDate::from_ymd_opt(2026, 4, day).unwrap(),
+            portions,
+            at: Position { line: 3, column: 1 },
+        }
+    }
+
+    #[test]
+    fn test_serve_oldest_surplus_first() {
+        let mut leftovers = Leftovers {
+            pending: vec![surplus("Gulasch", 2, 8.0), surplus("Nudeln", 3, 6.0)],
+        };
+
+        let meal = leftovers.serve("Samstag, 04.04.2026".to_string(), 10);
+        assert!(meal.covered());
+        assert_eq!(
+            meal.fed,
+            [surplus("Gulasch", 2, 8.0), surplus("Nudeln", 3, 2.0)]
+        );
+        assert_eq!(leftovers.uneaten(), [surplus("Nudeln", 3, 4.0)]);
+
+        let meal = leftovers.serve("Sonntag, 05.04.2026".to_string(), 10);
+        assert!(!meal.covered());
+        assert!(leftovers.uneaten().is_empty());
+
+        assert_eq!(
+            as_markdown(&[meal]),
+            "| Reste | Personen | Portionen | Gedeckt | Aus |\n\
+             | --- | ---: | ---: | --- | --- |\n\
+             | Sonntag, 05.04.2026 | 10 | 4 | nein | Nudeln vom 03.04. (4) |\n"
+        );
+    }
+}
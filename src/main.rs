@@ -1,37 +1,284 @@
 #![allow(unreachable_code)]
+mod aliases;
+mod allergens;
+mod attendance;
+mod categories;
+mod checklist;
 mod cli;
 mod cookbook;
+mod diagnostics;
 mod dish;
 mod error;
+mod extras;
+mod groups;
+mod leftovers;
+mod nutrition;
+mod packages;
+mod pantry;
+mod pdf;
 mod plan;
+mod prices;
+mod quantity;
+mod scaling;
+mod substitutions;
+mod table;
+mod trip;
 mod types;
+mod units;
 
 use clap::Parser;
 use cli::Cli;
 use plan::Plan;
-use std::fs;
+use std::{fs, path::Path, process::ExitCode};
 
-use crate::{cookbook::CookBook, plan::WeekPlan};
+use crate::{
+    aliases::Aliases,
+    allergens::AllergenTable,
+    attendance::Attendance,
+    categories::Categories,
+    checklist::Checklist,
+    cookbook::CookBook,
+    dish::Dish,
+    extras::Extras,
+    nutrition::NutrientTable,
+    packages::Packages,
+    pantry::Pantry,
+    plan::WeekPlan,
+    prices::Prices,
+    scaling::ScalingRules,
+    substitutions::Substitutions,
+    trip::{Marker, ShoppingTrip},
+    units::UnitRegistry,
+};
 
-fn main() {
-    let Cli { plan, dish_root } = Cli::parse();
+fn main() -> ExitCode {
+    let Cli {
+        plan,
+        dish_root,
+        aliases,
+        substitutions,
+        categories,
+        nutrition,
+        allergens,
+        prices,
+        packages,
+        scaling,
+        units,
+        attendance,
+        pantry,
+        extras,
+        ranges,
+        clustered,
+        fresh,
+        allergen_matrix,
+        head_counts,
+        leftovers,
+        pdf,
+        lint_units,
+        strict,
+    } = Cli::parse();
 
-    let cookbook = CookBook::from_file(&dish_root);
-    let week_plan = WeekPlan::from_file(&plan, &cookbook);
-    let shopping_lists = week_plan.shopping_lists();
+    let substitutions =
+        Substitutions::from_file(&substitutions).expect("Failed to read substitutions");
+    let aliases = Aliases::from_file(&aliases).expect("Failed to read aliases");
+    let nutrients = NutrientTable::from_file(&nutrition).expect("Failed to read nutrition");
+    let allergens = AllergenTable::from_file(&allergens).expect("Failed to read allergens");
+    let prices = Prices::from_file(&prices).expect("Failed to read prices");
+    let (rules, rejected) =
+        ScalingRules::from_file(&scaling).expect("Failed to read scaling rules");
+    for diagnostic in &rejected {
+        eprintln!("{}:{diagnostic}", scaling.display());
+    }
+    let attendance = Attendance::from_file(&attendance).expect("Failed to read attendance");
+    let units = UnitRegistry::from_file(&units).expect("Failed to read units");
+    let cookbook = CookBook::from_file(&dish_root)
+        .with_substitutions(substitutions)
+        .with_aliases(aliases)
+        .with_nutrients(nutrients)
+        .with_allergens(allergens)
+        .with_prices(prices)
+        .with_range_policy(ranges)
+        .with_scaling(rules)
+        .with_attendance(attendance)
+        .with_units(units);
+
+    if lint_units {
+        return lint(&cookbook);
+    }
+
+    let packages = Packages::from_file(&packages).expect("Failed to read packages");
+    let categories = Categories::from_file(&categories).expect("Failed to read categories");
+    let mut pantry = Pantry::from_file(&pantry).expect("Failed to read pantry");
+    let checklist = if fresh {
+        Checklist::default()
+    } else {
+        Checklist::from_file(Path::new("./shopping-list.md"))
+            .expect("Failed to read shopping-list.md")
+    };
+    let week_plan = match WeekPlan::from_file(&plan, &cookbook) {
+        Ok(week_plan) => week_plan,
+        Err(e) => {
+            eprintln!("{e:#}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut shopping_lists = week_plan.shopping_lists();
+    for source in &extras {
+        let extra = Extras::from_file(source).expect("Failed to read extra list");
+        let position = match &source.trip {
+            Some(label) => shopping_lists.iter().position(|trip| trip.label == *label),
+            None => Some(0),
+        };
+        match position {
+            Some(i) => shopping_lists[i].merge(extra),
+            None => {
+                let marker = Marker {
+                    label: source.trip.clone().unwrap_or_default(),
+                    ..Default::default()
+                };
+                let mut trip = ShoppingTrip::new(&marker, week_plan.start);
+                trip.merge(extra);
+                shopping_lists.push(trip);
+            }
+        }
+    }
+
+    for trip in &mut shopping_lists {
+        cookbook.aliases.apply(&mut trip.list);
+        trip.list.accumulate();
+    }
+    pantry.supply(&mut shopping_lists, &cookbook.aliases);
 
     // Generate concatenated markdown with numbered sections
     let mut output = String::new();
-    for (i, mut list) in shopping_lists.into_iter().enumerate() {
-        let section_number = i + 1;
-        output.push_str(&format!("## Einkauf {}\n\n", section_number));
-        output.push_str(&list.as_md_list());
+    let mut shopping_pages = Vec::new();
+    let mut grand_total = 0.0;
+    for trip in shopping_lists {
+        let date_line = trip.date_line();
+        let perishables = trip.perishables_as_markdown(&categories);
+        let stocked = pantry::as_markdown(&trip.stocked);
+        let (label, mut list, extra) = (trip.label, trip.list, trip.extras);
+        let progress = checklist.reconcile(&label, &mut list, &cookbook.aliases);
+        packages.round(&mut list, &cookbook.aliases);
+        let mut items = if clustered {
+            list.as_clustered_md_list(&categories)
+        } else {
+            list.as_md_list()
+        };
+        if let Some(date_line) = date_line {
+            items = format!("{date_line}\n\n{items}");
+        }
+        if !stocked.is_empty() {
+            items.push_str("\n\n");
+            items.push_str(&stocked);
+        }
+        let extra: Vec<String> = extra
+            .into_iter()
+            .filter(|line| !progress.has_manual(line))
+            .collect();
+        if !extra.is_empty() {
+            items.push_str("\n\n### Weitere Einträge\n");
+            items.push_str(&extra.join("\n"));
+        }
+        if !perishables.is_empty() {
+            items.push_str("\n\n");
+            items.push_str(&perishables);
+        }
+        let done = progress.as_markdown();
+        if !done.is_empty() {
+            items.push_str("\n\n");
+            items.push_str(&done);
+        }
+        if let Some(prices) = &cookbook.prices {
+            let bought = list.0.iter().chain(&progress.done).cloned();
+            let costs = prices.costs(&bought.collect::<Vec<_>>(), &cookbook.aliases);
+            grand_total += costs.total();
+            items.push_str("\n\n");
+            items.push_str(&costs.as_markdown(&format!("Summe {}", label)));
+        }
+        output.push_str(&format!("## {}\n\n", label));
+        output.push_str(&items);
         output.push_str("\n\n");
+        shopping_pages.push(pdf::Page {
+            header: label,
+            markdown: items,
+        });
+    }
+
+    if cookbook.prices.is_some() {
+        output.push_str(&format!(
+            "## Gesamtkosten\n\n**{}**\n",
+            prices::euro(grand_total)
+        ));
     }
 
     fs::write("./shopping-list.md", &output).expect("Failed to write shopping-list.md");
     fs::write("./dishes.md", week_plan.dishes_as_markdown())
         .expect("Failed to write shopping-list.md");
 
+    if pdf {
+        pdf::render(
+            "Einkaufsliste",
+            &shopping_pages,
+            Path::new("./shopping-list.pdf"),
+        )
+        .expect("Failed to write shopping-list.pdf");
+        let title = format!("Rezepte ab {}", week_plan.start.format("%d.%m.%Y"));
+        pdf::render(&title, &week_plan.dish_pages(), Path::new("./dishes.pdf"))
+            .expect("Failed to write dishes.pdf");
+    }
+
     println!("Shopping lists generated successfully!");
+
+    if head_counts {
+        println!("\n{}", week_plan.head_counts());
+    }
+
+    if leftovers {
+        println!("\n{}", week_plan.leftovers());
+    }
+
+    if allergen_matrix {
+        if cookbook.allergens.is_some() {
+            println!("\n{}", week_plan.allergen_matrix());
+        } else {
+            eprintln!("No allergen table found, skipping the allergen matrix");
+        }
+    }
+
+    if !week_plan.diagnostics.is_empty() {
+        eprint!("{}", week_plan.diagnostics.summary());
+    }
+    if strict && !(week_plan.diagnostics.is_empty() && rejected.is_empty()) {
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Print the ingredient names of all dishes that start with a probable unit.
+fn lint(cookbook: &CookBook) -> ExitCode {
+    let mut findings = 0;
+    for (name, path) in cookbook.dishes() {
+        let is_recipe = path
+            .extension()
+            .is_some_and(|extension| extension == "md" || extension == "txt");
+        if !is_recipe {
+            continue;
+        }
+        // Files that are not dishes at all are no concern of this lint.
+        let Ok(dish) = Dish::from_file_with_units(path, name, 1, &cookbook.units) else {
+            continue;
+        };
+        for issue in dish.probable_units(&cookbook.units) {
+            println!("{}: {issue}", path.display());
+            findings += 1;
+        }
+    }
+
+    if findings > 0 {
+        eprintln!("{findings} ingredient(s) start with a probable unit");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,315 @@
+use std::{collections::HashMap, fs, ops::Add, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    aliases::Aliases,
+    types::Ingredient,
+    units::{Family, Unit},
+};
+
+/// Grams assumed for a single teaspoon.
+const GRAMS_PER_TEASPOON: f32 = 5.0;
+
+/// Nutrient values, either per 100 g or as absolute amounts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Nutrients {
+    pub(crate) kcal: f32,
+    pub(crate) protein: f32,
+    pub(crate) fat: f32,
+    pub(crate) saturated_fat: f32,
+    pub(crate) carbs: f32,
+    pub(crate) sugar: f32,
+    pub(crate) fiber: f32,
+    pub(crate) salt: f32,
+}
+
+impl Nutrients {
+    fn scale(self, factor: f32) -> Self {
+        Self {
+            kcal: self.kcal * factor,
+            protein: self.protein * factor,
+            fat: self.fat * factor,
+            saturated_fat: self.saturated_fat * factor,
+            carbs: self.carbs * factor,
+            sugar: self.sugar * factor,
+            fiber: self.fiber * factor,
+            salt: self.salt * factor,
+        }
+    }
+}
+
+impl Add for Nutrients {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            kcal: self.kcal + rhs.kcal,
+            protein: self.protein + rhs.protein,
+            fat: self.fat + rhs.fat,
+            saturated_fat: self.saturated_fat + rhs.saturated_fat,
+            carbs: self.carbs + rhs.carbs,
+            sugar: self.sugar + rhs.sugar,
+            fiber: self.fiber + rhs.fiber,
+            salt: self.salt + rhs.salt,
+        }
+    }
+}
+
+/// Table entry of a single ingredient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Entry {
+    /// Values per 100 g.
+    per_100g: Nutrients,
+    /// Weight of one piece, used for ingredients counted in pieces.
+    piece_grams: Option<f32>,
+}
+
+/// Local nutrient table keyed by canonical ingredient name.
+///
+/// Read from a markdown table with the columns
+///
+/// ```text
+/// | Zutat | kcal | Eiweiß | Fett | ges. Fett | Kohlenhydrate | Zucker | Ballaststoffe | Salz | g/Stück |
+/// ```
+///
+/// All values are per 100 g, the piece weight may be left empty.
+#[derive(Debug, Default)]
+pub(crate) struct NutrientTable {
+    entries: HashMap<String, Entry>,
+}
+
+impl NutrientTable {
+    /// Load the table, returns `None` if the file does not exist.
+    pub(crate) fn from_file(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read nutrition file: {}", path.display()))?;
+        Ok(Some(Self::parse(&content)))
+    }
+
+    /// Parse table rows, the header and malformed rows are skipped.
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut entries = HashMap::new();
+
+        for line in content.lines().map(str::trim) {
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+            if cells.len() < 9 {
+                continue;
+            }
+            let values: Option<Vec<f32>> = cells[1..9]
+                .iter()
+                .map(|cell| cell.replace(',', ".").parse().ok())
+                .collect();
+            let Some(values) = values else {
+                continue;
+            };
+
+            entries.insert(
+                cells[0].to_lowercase(),
+                Entry {
+                    per_100g: Nutrients {
+                        kcal: values[0],
+                        protein: values[1],
+                        fat: values[2],
+                        saturated_fat: values[3],
+                        carbs: values[4],
+                        sugar: values[5],
+                        fiber: values[6],
+                        salt: values[7],
+                    },
+                    piece_grams: cells
+                        .get(9)
+                        .and_then(|cell| cell.replace(',', ".").parse().ok()),
+                },
+            );
+        }
+
+        Self { entries }
+    }
+
+    /// Compute the nutrients of a list of ingredients that feeds `people`.
+    pub(crate) fn nutrition(
+        &self,
+        ingredients: &[Ingredient],
+        people: usize,
+        aliases: &Aliases,
+    ) -> Nutrition {
+        let mut nutrition = Nutrition {
+            people,
+            ..Default::default()
+        };
+
+        for ingredient in ingredients {
+            let entry = self
+                .entries
+                .get(&aliases.canonical(&ingredient.name).to_lowercase());
+            match entry.and_then(|entry| grams(ingredient, entry).map(|g| (entry, g))) {
+                Some((entry, grams)) => {
+                    nutrition.total = nutrition.total + entry.per_100g.scale(grams / 100.0);
+                    nutrition.grams += grams;
+                }
+                None => nutrition.missing.push(ingredient.name.clone()),
+            }
+        }
+
+        nutrition
+    }
+}
+
+/// Weight of an ingredient in grams, volumes are taken as water.
+fn grams(ingredient: &Ingredient, entry: &Entry) -> Option<f32> {
+    match Unit::parse(&ingredient.measure) {
+        Some(unit) => match unit.family {
+            Family::Mass | Family::Volume => Some(ingredient.amount * unit.factor),
+            Family::Spoon => Some(ingredient.amount * unit.factor * GRAMS_PER_TEASPOON),
+            Family::Count => entry.piece_grams.map(|g| ingredient.amount * g),
+        },
+        None if ingredient.measure.is_empty() => entry.piece_grams.map(|g| ingredient.amount * g),
+        None => None,
+    }
+}
+
+/// Nutrients of a dish.
+#[derive(Debug, Default)]
+pub(crate) struct Nutrition {
+    /// Sum over all ingredients with known values.
+    pub(crate) total: Nutrients,
+    /// Weight of all ingredients with known values.
+    pub(crate) grams: f32,
+    /// Number of people the totals are meant for.
+    pub(crate) people: usize,
+    /// Ingredients without nutrient data.
+    pub(crate) missing: Vec<String>,
+}
+
+impl Nutrition {
+    pub(crate) fn per_person(&self) -> Nutrients {
+        self.total.scale(1.0 / self.people.max(1) as f32)
+    }
+
+    /// Nutri-Score style grade from A to E, computed per 100 g.
+    ///
+    /// Fruit and vegetable shares are unknown and not rewarded.
+    pub(crate) fn score(&self) -> Option<char> {
+        if self.grams <= 0.0 {
+            return None;
+        }
+        let per_100g = self.total.scale(100.0 / self.grams);
+
+        let kj = per_100g.kcal * 4.184;
+        let sodium_mg = per_100g.salt * 400.0;
+        let negative = points(kj, 335.0, 10)
+            + points(per_100g.sugar, 4.5, 10)
+            + points(per_100g.saturated_fat, 1.0, 10)
+            + points(sodium_mg, 90.0, 10);
+        let fiber = points(per_100g.fiber, 0.9, 5);
+        let protein = points(per_100g.protein, 1.6, 5);
+
+        let score = if negative >= 11 {
+            negative as i32 - fiber as i32
+        } else {
+            negative as i32 - fiber as i32 - protein as i32
+        };
+
+        Some(match score {
+            i32::MIN..=-1 => 'A',
+            0..=2 => 'B',
+            3..=10 => 'C',
+            11..=18 => 'D',
+            _ => 'E',
+        })
+    }
+
+    /// Single markdown line with the values per person.
+    pub(crate) fn as_markdown(&self) -> String {
+        let n = self.per_person();
+        let mut line = format!(
+            "**Nährwerte pro Person:** {:.0} kcal · {:.0} g Eiweiß · {:.0} g Fett · {:.0} g Kohlenhydrate",
+            n.kcal, n.protein, n.fat, n.carbs
+        );
+        if let Some(score) = self.score() {
+            line.push_str(&format!(" · Nutri-Score {score}"));
+        }
+        if !self.missing.is_empty() {
+            line.push_str(&format!(" (ohne {})", self.missing.join(", ")));
+        }
+        line
+    }
+}
+
+/// Points for a value, one point per full step, capped at `max`.
+fn points(value: f32, step: f32, max: u32) -> u32 {
+    ((value / step).ceil().max(1.0) as u32 - 1).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_ingredient(amount: f32, measure: &str, name: &str) -> Ingredient {
+        Ingredient {
+            amount,
+            measure: measure.to_string(),
+            name: name.to_string(),
+            dish: "Dish".to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    const TABLE: &str = r#"
+| Zutat  | kcal | Eiweiß | Fett | ges. Fett | Kohlenhydrate | Zucker | Ballaststoffe | Salz | g/Stück |
+| ------ | ---- | ------ | ---- | --------- | ------------- | ------ | ------------- | ---- | ------- |
+| Mehl   | 350  | 10     | 1    | 0,2       | 72            | 0,7    | 4             | 0    |         |
+| Ei     | 155  | 13     | 11   | 3,3       | 1,1           | 1,1    | 0             | 0,3  | 60      |
+| Butter | 740  | 0,7    | 82   | 54        | 0,6           | 0,6    | 0             | 0    |         |
+"#;
+
+    #[test]
+    fn test_parse_table() {
+        let table = NutrientTable::parse(TABLE);
+
+        assert_eq!(table.entries.len(), 3);
+        assert_eq!(table.entries["ei"].piece_grams, Some(60.0));
+        assert_eq!(table.entries["mehl"].per_100g.saturated_fat, 0.2);
+    }
+
+    #[test]
+    fn test_nutrition_totals_and_missing() {
+        let table = NutrientTable::parse(TABLE);
+        let aliases = Aliases::parse("Ei: Eier\n");
+        let ingredients = vec![
+            make_ingredient(0.5, "kg", "Mehl"),
+            make_ingredient(2.0, "Stück", "Eier"),
+            make_ingredient(1.0, "EL", "Butter"),
+            make_ingredient(1.0, "Prise", "Salz"),
+        ];
+
+        let nutrition = table.nutrition(&ingredients, 4, &aliases);
+
+        assert_eq!(nutrition.missing, vec!["Salz".to_string()]);
+        assert!((nutrition.grams - 635.0).abs() < 1e-3);
+        // 500 g Mehl + 120 g Ei + 15 g Butter
+        let kcal = 1750.0 + 186.0 + 111.0;
+        assert!((nutrition.total.kcal - kcal).abs() < 1e-2);
+        assert!((nutrition.per_person().kcal - kcal / 4.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_score() {
+        let table = NutrientTable::parse(TABLE);
+        let aliases = Aliases::default();
+
+        let butter = table.nutrition(&[make_ingredient(100.0, "g", "Butter")], 1, &aliases);
+        assert_eq!(butter.score(), Some('E'));
+
+        let flour = table.nutrition(&[make_ingredient(100.0, "g", "Mehl")], 1, &aliases);
+        assert_eq!(flour.score(), Some('A'));
+
+        let nothing = table.nutrition(&[], 1, &aliases);
+        assert_eq!(nothing.score(), None);
+    }
+}
@@ -0,0 +1,232 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    aliases::Aliases,
+    types::IngredientList,
+    units::{convert, Family, Unit},
+};
+
+/// Count measures rounded up to whole numbers unless configured otherwise.
+const BUILTIN_STEPS: &[(&str, f32)] = &[
+    ("Stück", 1.0),
+    ("Dose", 1.0),
+    ("Glas", 1.0),
+    ("Packung", 1.0),
+    ("Bund", 1.0),
+    ("Becher", 1.0),
+];
+
+/// Rounded purchase of an ingredient in whole packages.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Packed {
+    /// Number of packages to buy.
+    pub(crate) count: u32,
+    /// Size of a single package.
+    pub(crate) size: f32,
+    /// Unit of the package size.
+    pub(crate) measure: String,
+}
+
+/// Purchasable package sizes and rounding steps for count measures.
+///
+/// Read from a markdown file with two sections:
+///
+/// ```text
+/// ## Packungen
+/// - Butter: 250 g
+/// - Mais: 1 Dose
+///
+/// ## Rundung
+/// - Zehe: 0,5
+/// ```
+///
+/// Package sizes are keyed by canonical ingredient name. Rounding steps apply
+/// to count measures of ingredients without a package size, an empty measure
+/// counts as "Stück".
+#[derive(Debug)]
+pub(crate) struct Packages {
+    sizes: HashMap<String, (f32, String)>,
+    steps: HashMap<String, f32>,
+}
+
+impl Default for Packages {
+    fn default() -> Self {
+        Self {
+            sizes: HashMap::new(),
+            steps: BUILTIN_STEPS
+                .iter()
+                .map(|(measure, step)| (measure.to_lowercase(), *step))
+                .collect(),
+        }
+    }
+}
+
+impl Packages {
+    /// Load the package sizes, a missing file yields only the built-in rounding steps.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read package file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut packages = Self::default();
+        let mut rounding = false;
+
+        for line in content.lines().map(str::trim) {
+            if let Some(heading) = line.strip_prefix("##") {
+                rounding = heading.trim().eq_ignore_ascii_case("Rundung");
+                continue;
+            }
+            let line = line.trim_start_matches("- ");
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, value)) = line.split_once(':') else {
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+
+            if rounding {
+                if let Some(step) = parse_number(value).filter(|step| *step > 0.0) {
+                    packages.steps.insert(name.to_lowercase(), step);
+                }
+                continue;
+            }
+
+            let (size, measure) = match value.split_once(' ') {
+                Some((size, measure)) => (parse_number(size), measure.trim()),
+                None => (parse_number(value), ""),
+            };
+            if let Some(size) = size.filter(|size| *size > 0.0) {
+                packages
+                    .sizes
+                    .insert(name.to_lowercase(), (size, measure.to_string()));
+            }
+        }
+
+        packages
+    }
+
+    /// Round an accumulated list up to whole packages.
+    ///
+    /// Ingredients with a package size get a [`Packed`] purchase and keep the
+    /// needed amount. Count measures without one are rounded up to their step.
+    pub(crate) fn round(&self, list: &mut IngredientList, aliases: &Aliases) {
+        for ingredient in list.0.iter_mut() {
+            let name = aliases.canonical(&ingredient.name).to_lowercase();
+
+            let size = self.sizes.get(&name).and_then(|(size, measure)| {
+                convert(ingredient.amount, &ingredient.measure, measure)
+                    .map(|amount| (amount, *size, measure))
+            });
+            if let Some((amount, size, measure)) = size {
+                ingredient.packed = Some(Packed {
+                    count: (amount / size - 1e-4).ceil().max(1.0) as u32,
+                    size,
+                    measure: measure.clone(),
+                });
+            } else if let Some(step) = self.step(&ingredient.measure) {
+                ingredient.amount = round_up(ingredient.amount, step);
+            }
+        }
+    }
+
+    /// Rounding step of a count measure.
+    fn step(&self, measure: &str) -> Option<f32> {
+        let key = match Unit::parse(measure) {
+            Some(unit) if unit.family == Family::Count => unit.symbol.to_lowercase(),
+            Some(_) => return None,
+            None if measure.trim().is_empty() => "stück".to_string(),
+            None => measure.trim().to_lowercase(),
+        };
+        self.steps.get(&key).copied()
+    }
+}
+
+/// Round up to a multiple of `step`, ignoring float noise.
+fn round_up(amount: f32, step: f32) -> f32 {
+    (amount / step - 1e-4).ceil().max(0.0) * step
+}
+
+fn parse_number(text: &str) -> Option<f32> {
+    text.replace(',', ".").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Ingredient;
+
+    fn make_ingredient(amount: f32, measure: &str, name: &str) -> Ingredient {
+        Ingredient {
+            amount,
+            measure: measure.to_string(),
+            name: name.to_string(),
+            dish: "Dish".to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    const PACKAGES: &str = r#"
+## Packungen
+- Butter: 250 g
+- Mais: 1 Dose
+- Ei: 10
+
+## Rundung
+- Zehe: 0,5
+"#;
+
+    #[test]
+    fn test_parse_sizes_and_steps() {
+        let packages = Packages::parse(PACKAGES);
+
+        assert_eq!(packages.sizes["butter"], (250.0, "g".to_string()));
+        assert_eq!(packages.sizes["ei"], (10.0, String::new()));
+        assert_eq!(packages.steps["zehe"], 0.5);
+        assert_eq!(packages.steps["dose"], 1.0);
+    }
+
+    #[test]
+    fn test_round_to_packages() {
+        let packages = Packages::parse(PACKAGES);
+        let mut list = IngredientList::from(vec![
+            make_ingredient(1.1, "kg", "Butter"),
+            make_ingredient(3.0, "Dose", "Mais"),
+            make_ingredient(12.0, "Stück", "Eier"),
+            make_ingredient(21.5, "", "Möhren"),
+            make_ingredient(107.5, "Stück", "Würstchenalternative"),
+            make_ingredient(2.2, "Zehe", "Knoblauch"),
+            make_ingredient(1.3, "Prise", "Salz"),
+            make_ingredient(1.3, "kg", "Mehl"),
+        ]);
+
+        packages.round(&mut list, &Aliases::parse("Ei: Eier\n"));
+
+        let butter = &list.0[0];
+        assert_eq!(
+            butter.packed,
+            Some(Packed {
+                count: 5,
+                size: 250.0,
+                measure: "g".to_string(),
+            })
+        );
+        assert_eq!(butter.amount, 1.1);
+        assert_eq!(list.0[1].packed.as_ref().unwrap().count, 3);
+        assert_eq!(list.0[2].packed.as_ref().unwrap().count, 2);
+        assert_eq!(list.0[3].amount, 22.0);
+        assert_eq!(list.0[4].amount, 108.0);
+        assert_eq!(list.0[5].amount, 2.5);
+        assert_eq!(list.0[6].amount, 1.3);
+        assert_eq!(list.0[7].amount, 1.3);
+        assert!(list.0[7].packed.is_none());
+    }
+}
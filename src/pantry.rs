@@ -0,0 +1,259 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+
+use crate::{
+    aliases::Aliases,
+    trip::ShoppingTrip,
+    types::{Ingredient, IngredientList},
+    units::convert,
+};
+
+/// Heading of the block with the items taken from the storeroom.
+pub(crate) const HEADING: &str = "Aus dem Lager";
+
+/// An item left in the storeroom.
+#[derive(Debug, Clone, PartialEq)]
+struct Stock {
+    name: String,
+    amount: f32,
+    /// Unit of the amount, empty for pieces.
+    measure: String,
+    /// Last day the item can be used, if it goes off.
+    expires: Option<NaiveDate>,
+}
+
+/// Stock take of the storeroom before a camp.
+///
+/// Read from a markdown table with the amount, its unit and an optional
+/// best-before date:
+///
+/// ```text
+/// | Zutat   | Menge | Einheit | Haltbar bis |
+/// | Mehl    | 2,5   | kg      |             |
+/// | Milch   | 6     | l       | 2026-04-05  |
+/// | Eier    | 20    |         | 04.04.2026  |
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct Pantry {
+    stock: Vec<Stock>,
+}
+
+impl Pantry {
+    /// Load the stock, a missing file yields an empty storeroom.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read pantry file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse table rows, the header and malformed rows are skipped.
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut stock = Vec::new();
+
+        for line in content.lines().map(str::trim) {
+            if !line.starts_with('|') {
+                continue;
+            }
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+            let (name, amount, measure, expires) = match cells[..] {
+                [name, amount, measure] => (name, amount, measure, ""),
+                [name, amount, measure, expires] => (name, amount, measure, expires),
+                _ => continue,
+            };
+            let Ok(amount) = amount.replace(',', ".").parse() else {
+                continue;
+            };
+
+            stock.push(Stock {
+                name: name.to_string(),
+                amount,
+                measure: measure.to_string(),
+                expires: parse_date(expires),
+            });
+        }
+
+        Self { stock }
+    }
+
+    /// Take the stock off the lists of all trips, earliest trip first.
+    ///
+    /// The lists have to be accumulated. What each trip gets from the
+    /// storeroom is stored in its `stocked` items.
+    pub(crate) fn supply(&mut self, trips: &mut [ShoppingTrip], aliases: &Aliases) {
+        let mut order: Vec<usize> = (0..trips.len()).collect();
+        order.sort_by_key(|&i| trips[i].date);
+        for i in order {
+            let trip = &mut trips[i];
+            trip.stocked = self.take(&mut trip.list, aliases, trip.date);
+        }
+    }
+
+    /// Take the stock needed for a list bought on `date` off the list.
+    ///
+    /// Items that are fully in stock are removed from the list, others keep
+    /// the missing amount. Stock that expired before `date` is not used.
+    /// Returns the amounts taken from the storeroom.
+    pub(crate) fn take(
+        &mut self,
+        list: &mut IngredientList,
+        aliases: &Aliases,
+        date: NaiveDate,
+    ) -> Vec<Ingredient> {
+        let mut taken = Vec::new();
+
+        for ingredient in list.0.iter_mut() {
+            let name = aliases.canonical(&ingredient.name).to_lowercase();
+            let mut supplied = 0.0;
+            for stock in self.stock.iter_mut() {
+                if stock.amount <= 1e-4
+                    || stock.expires.is_some_and(|expires| expires < date)
+                    || aliases.canonical(&stock.name).to_lowercase() != name
+                {
+                    continue;
+                }
+                let Some(available) = convert(stock.amount, &stock.measure, &ingredient.measure)
+                else {
+                    continue;
+                };
+                let used = available.min(ingredient.amount - supplied);
+                supplied += used;
+                stock.amount -= stock.amount * used / available;
+                if ingredient.amount - supplied <= 1e-4 {
+                    break;
+                }
+            }
+            if supplied > 0.0 {
+                taken.push(Ingredient {
+                    amount: supplied,
+                    ..ingredient.clone()
+                });
+                ingredient.amount -= supplied;
+            }
+        }
+        list.0.retain(|ingredient| ingredient.amount > 1e-4);
+
+        taken
+    }
+}
+
+/// Block listing the items to take from the storeroom, empty if there are none.
+pub(crate) fn as_markdown(taken: &[Ingredient]) -> String {
+    if taken.is_empty() {
+        return String::new();
+    }
+    let mut items: Vec<String> = taken
+        .iter()
+        .map(|ingredient| {
+            let amount = if ingredient.measure.is_empty() {
+                format!("{:.1}", ingredient.amount)
+            } else {
+                format!("{:.1} {}", ingredient.amount, ingredient.measure)
+            };
+            format!("- {}: {} ({})", ingredient.name, amount, ingredient.dish)
+        })
+        .collect();
+    items.sort();
+    format!("### {HEADING}\n{}", items.join("\n"))
+}
+
+/// A best-before date written as "2026-04-05" or "05.04.2026".
+fn parse_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(text, "%d.%m.%Y"))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 4, day).unwrap()
+    }
+
+    fn ingredient(name: &str, amount: f32, measure: &str) -> Ingredient {
+        Ingredient {
+            amount,
+            measure: measure.to_string(),
+            name: name.to_string(),
+            dish: "Kuchen".to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    const CONTENT: &str = "# Lager\n\n\
+        | Zutat  | Menge | Einheit | Haltbar bis |\n\
+        | ------ | ----- | ------- | ----------- |\n\
+        | Mehl   | 1,5   | kg      |             |\n\
+        | Milch  | 2     | l       | 2026-04-03  |\n\
+        | Eier   | 20    |         | 10.04.2026  |\n\
+        | Zucker | viel  | kg      |             |\n";
+
+    #[test]
+    fn test_parse_stock() {
+        let pantry = Pantry::parse(CONTENT);
+
+        assert_eq!(pantry.stock.len(), 3);
+        assert_eq!(
+            pantry.stock[0],
+            Stock {
+                name: "Mehl".to_string(),
+                amount: 1.5,
+                measure: "kg".to_string(),
+                expires: None,
+            }
+        );
+        assert_eq!(pantry.stock[1].expires, Some(date(3)));
+        assert_eq!(pantry.stock[2].expires, Some(date(10)));
+    }
+
+    #[test]
+    fn test_missing_file_has_no_stock() {
+        let pantry = Pantry::from_file(Path::new("does-not-exist.md")).unwrap();
+        assert!(pantry.stock.is_empty());
+
+        let pantry = Pantry::parse(include_str!("test-data/pantry.md"));
+        assert_eq!(pantry.stock.len(), 3);
+        assert_eq!(pantry.stock[2].name, "Haferdrink");
+    }
+
+    #[test]
+    fn test_take_consumes_stock_in_order() {
+        let mut pantry = Pantry::parse(CONTENT);
+        let aliases = Aliases::parse("Ei: Eier\n");
+
+        let mut first = IngredientList::from(vec![
+            ingredient("Mehl", 1000.0, "g"),
+            ingredient("Ei", 12.0, ""),
+            ingredient("Milch", 1.0, "l"),
+        ]);
+        let taken = pantry.take(&mut first, &aliases, date(2));
+        assert_eq!(taken.len(), 3);
+        assert!(first.0.is_empty());
+
+        // Only 500 g Mehl and 8 Eier are left, the Milch has expired.
+        let mut second = IngredientList::from(vec![
+            ingredient("Mehl", 800.0, "g"),
+            ingredient("Ei", 6.0, ""),
+            ingredient("Milch", 1.0, "l"),
+        ]);
+        let taken = pantry.take(&mut second, &aliases, date(4));
+        assert_eq!(second.0.len(), 2);
+        assert!((second.0[0].amount - 300.0).abs() < 1e-3);
+        assert_eq!(second.0[1].name, "Milch");
+        assert_eq!(taken.len(), 2);
+        assert!((taken[0].amount - 500.0).abs() < 1e-3);
+
+        assert_eq!(
+            as_markdown(&taken),
+            "### Aus dem Lager\n- Ei: 6.0 (Kuchen)\n- Mehl: 500.0 g (Kuchen)"
+        );
+        assert_eq!(as_markdown(&[]), "");
+    }
+}
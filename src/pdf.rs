@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+/// A single page of an exported document.
+pub(crate) struct Page {
+    /// Heading printed on top of the page.
+    pub(crate) header: String,
+    /// Markdown content of the page.
+    pub(crate) markdown: String,
+}
+
+/// Render the pages into a PDF with markdown2pdf.
+///
+/// markdown2pdf cannot break pages, so the PDF is one flowing document, see
+/// [`sections`].
+pub(crate) fn render(title: &str, pages: &[Page], path: &Path) -> Result<()> {
+    markdown2pdf::parse(sections(title, pages), &path.to_string_lossy())
+        .map_err(|e| anyhow!("Failed to write PDF {}: {e}", path.display()))
+}
+
+/// Markdown of the whole document below the title.
+///
+/// Every page becomes a section under its header, separated from the previous
+/// one by a horizontal rule. Consecutive pages with the same header, like the
+/// dishes of one day, share a section.
+fn sections(title: &str, pages: &[Page]) -> String {
+    let mut markdown = format!("# {title}\n");
+    let mut header = None;
+
+    for page in pages {
+        if header != Some(&page.header) {
+            markdown.push_str(&format!("\n---\n\n# {}\n", page.header));
+            header = Some(&page.header);
+        }
+        markdown.push('\n');
+        markdown.push_str(&checkboxes(&page.markdown));
+    }
+
+    markdown
+}
+
+/// Write task list checkboxes as text, markdown2pdf would read `[ ]` as a link.
+fn checkboxes(markdown: &str) -> String {
+    markdown
+        .lines()
+        .map(|line| {
+            if let Some(item) = line.strip_prefix("- [ ] ") {
+                format!("- ( ) {item}")
+            } else if let Some(item) = line.strip_prefix("- [x] ") {
+                format!("- (x) {item}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_writes_pdf() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("out.pdf");
+        let pages = vec![
+            Page {
+                header: "Donnerstag, 02.04.2026".to_string(),
+                markdown: "## Gulasch (4 Personen)\n\n### Zutaten\n- 200.0 g Butter\n".to_string(),
+            },
+            Page {
+                header: "Einkauf 1".to_string(),
+                markdown: "- [ ] Butter: 200.0 g (Gulasch)\n- [x] Salz\n".to_string(),
+            },
+        ];
+
+        render("Test", &pages, &path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"%PDF"));
+        assert_eq!(
+            checkboxes(&pages[1].markdown),
+            "- ( ) Butter: 200.0 g (Gulasch)\n- (x) Salz\n"
+        );
+    }
+
+    #[test]
+    fn test_sections_share_headers() {
+        let page = |header: &str, markdown: &str| Page {
+            header: header.to_string(),
+            markdown: markdown.to_string(),
+        };
+        let pages = vec![
+            page("Donnerstag, 02.04.2026", "## Gulasch (4 Personen)\n"),
+            page("Donnerstag, 02.04.2026", "## Salat (4 Personen)\n"),
+            page("Freitag, 03.04.2026", "## Suppe (4 Personen)\n"),
+            page("Einkauf 1", "- [ ] Butter: 200.0 g (Gulasch)\n"),
+        ];
+
+        assert_eq!(
+            sections("Essensplan", &pages),
+            "# Essensplan\n\
+             \n---\n\n# Donnerstag, 02.04.2026\n\
+             \n## Gulasch (4 Personen)\n\
+             \n## Salat (4 Personen)\n\
+             \n---\n\n# Freitag, 03.04.2026\n\
+             \n## Suppe (4 Personen)\n\
+             \n---\n\n# Einkauf 1\n\
+             \n- ( ) Butter: 200.0 g (Gulasch)\n"
+        );
+    }
+}
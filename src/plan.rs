@@ -1,21 +1,96 @@
-use std::{fs, iter::Sum, ops::Add, path::Path};
+use std::{fs, iter::Sum, ops::Add, ops::Range, path::Path};
 
-use anyhow::Context;
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate, Weekday};
 use tree_sitter::Parser;
 
-use crate::{cookbook::CookBook, dish::Dish, types::IngredientList};
-
-pub(crate) trait Plan {
+use crate::{
+    allergens,
+    attendance::Schedule,
+    cookbook::CookBook,
+    diagnostics::{Issue, Position, Report},
+    dish::{Dish, VeganPortions},
+    groups::Groups,
+    leftovers::{self, Leftovers, RestMeal},
+    pdf::Page,
+    table::{self, Entry},
+    trip::{self, Marker, ShoppingTrip},
+    types::IngredientList,
+};
+
+pub(crate) trait Plan: Sized {
     /// Generate a shopping list for all dishes.
+    #[allow(dead_code)]
     fn shopping_list(&self) -> IngredientList;
-    fn from_file(path: &Path, cookbook: &CookBook) -> Self;
+    fn from_file(path: &Path, cookbook: &CookBook) -> Result<Self>;
 }
 
 /// A single day with multiple dishes.
+#[derive(Default)]
 pub(crate) struct Day {
+    /// Name of the day as written in the plan.
+    pub(crate) name: String,
+    /// Calendar date, counted from the plan's start date.
+    pub(crate) date: NaiveDate,
+    /// Head count of dishes without their own, from the day, attendance or plan.
+    pub(crate) people: usize,
     /// List of dishes.
     pub(crate) dishes: Vec<Dish>,
-    pub(crate) shopping_days: Vec<usize>,
+    /// Shopping markers, by index of the first dish they cover.
+    pub(crate) shopping_days: Vec<Marker>,
+    /// Meals of the day, only known for table plans.
+    pub(crate) slots: Vec<Slot>,
+    /// Reste meals of the day with the surplus feeding them.
+    pub(crate) leftovers: Vec<RestMeal>,
+}
+
+/// A meal of a day, e.g. "Abendessen".
+#[derive(Debug, Default)]
+pub(crate) struct Slot {
+    /// Name of the meal as written in the plan.
+    pub(crate) name: String,
+    /// Head count of the meal, if given or derived from the attendance.
+    pub(crate) people: Option<usize>,
+    /// Indices of the meal's dishes in the day.
+    pub(crate) dishes: Range<usize>,
+    /// Plain text entries without a recipe, e.g. "Maultaschen in Brühe".
+    pub(crate) notes: Vec<String>,
+}
+
+impl Day {
+    /// Weekday and date, e.g. "Dienstag, 02.04.2026".
+    ///
+    /// Names that are not a weekday, like "Anreise", are kept as written.
+    pub(crate) fn label(&self) -> String {
+        let name = if self.name.is_empty() || parse_weekday(&self.name).is_some() {
+            weekday_name(self.date.weekday())
+        } else {
+            &self.name
+        };
+        format!("{}, {}", name, self.date.format("%d.%m.%Y"))
+    }
+
+    /// Energy of all dishes per participant, if any dish has nutrient data.
+    pub(crate) fn kcal_per_person(&self) -> Option<f32> {
+        self.dishes
+            .iter()
+            .filter_map(|dish| dish.nutrition.as_ref())
+            .map(|nutrition| nutrition.per_person().kcal)
+            .reduce(|a, b| a + b)
+    }
+}
+
+/// German name of a weekday.
+pub(crate) fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Montag",
+        Weekday::Tue => "Dienstag",
+        Weekday::Wed => "Mittwoch",
+        Weekday::Thu => "Donnerstag",
+        Weekday::Fri => "Freitag",
+        Weekday::Sat => "Samstag",
+        Weekday::Sun => "Sonntag",
+    }
 }
 
 impl Plan for Day {
@@ -26,7 +101,7 @@ impl Plan for Day {
             .sum()
     }
 
-    fn from_file(_path: &Path, _cookbook: &CookBook) -> Self {
+    fn from_file(_path: &Path, _cookbook: &CookBook) -> Result<Self> {
         unimplemented!()
     }
 }
@@ -48,10 +123,12 @@ impl Sum for IngredientList {
 
 /// The week structure of a meal plan.
 pub(crate) struct WeekPlan {
-    /// Start date used for PDF export.
-    pub(crate) _start: chrono::NaiveDate,
+    /// Date of the first day.
+    pub(crate) start: NaiveDate,
     /// Consecutive list of days.
     pub(crate) days: Vec<Day>,
+    /// Problems found while resolving the plan.
+    pub(crate) diagnostics: Report,
 }
 
 impl WeekPlan {
@@ -59,76 +136,153 @@ impl WeekPlan {
     pub(crate) fn dishes_as_markdown(&self) -> String {
         let mut output = String::new();
 
-        for (day_idx, day) in self.days.iter().enumerate() {
-            if !day.dishes.is_empty() {
-                output.push_str(&format!("# Tag {}\n\n", day_idx + 1));
+        for day in &self.days {
+            let has_notes = day.slots.iter().any(|slot| !slot.notes.is_empty());
+            if !day.dishes.is_empty() || has_notes {
+                output.push_str(&format!("# {}\n\n", day.label()));
+                if let Some(kcal) = day.kcal_per_person() {
+                    output.push_str(&format!("Energie pro Person: {kcal:.0} kcal\n\n"));
+                }
 
-                for dish in &day.dishes {
-                    output.push_str(&dish.as_markdown());
-                    output.push_str("\n");
+                if day.slots.is_empty() {
+                    for dish in &day.dishes {
+                        output.push_str(&dish.as_markdown());
+                        output.push('\n');
+                    }
+                }
+                for slot in &day.slots {
+                    output.push_str(&slot_as_markdown(slot, &day.dishes));
                 }
 
-                output.push_str("\n");
+                output.push('\n');
             }
         }
 
         output
     }
 
-    /// Generate multiple shopping lists based on shopping markers across all days.
-    /// Shopping lists span multiple days until a shopping marker is encountered.
-    pub(crate) fn shopping_lists(&self) -> Vec<IngredientList> {
-        // Flatten all dishes across all days and collect shopping marker positions
-        let mut all_dishes = Vec::new();
-        let mut marker_positions = Vec::new();
-        let mut current_position = 0;
+    /// Allergens of each day's dishes as one markdown table per day.
+    ///
+    /// Dishes without labels, e.g. because they are not in the cookbook, are left out.
+    pub(crate) fn allergen_matrix(&self) -> String {
+        let mut output = String::new();
 
         for day in &self.days {
-            // Add all dishes from this day
-            all_dishes.extend(day.dishes.iter());
-
-            // Adjust shopping marker positions to account for all previous dishes
-            for &marker_idx in &day.shopping_days {
-                marker_positions.push(current_position + marker_idx);
+            let rows: Vec<String> = day
+                .dishes
+                .iter()
+                .filter_map(|dish| {
+                    let labels = dish.labels.as_ref()?;
+                    Some(format!(
+                        "| {} | {} |",
+                        dish.name,
+                        labels.cells().join(" | ")
+                    ))
+                })
+                .collect();
+            if rows.is_empty() {
+                continue;
             }
+            output.push_str(&format!("# {}\n\n", day.label()));
+            output.push_str(&allergens::matrix_header());
+            output.push('\n');
+            output.push_str(&rows.join("\n"));
+            output.push_str("\n\n");
+        }
+
+        output
+    }
 
-            current_position += day.dishes.len();
+    /// Head count of every day and meal as a markdown table.
+    ///
+    /// Plans without meals get a single column for the day.
+    pub(crate) fn head_counts(&self) -> String {
+        let mut meals: Vec<&str> = Vec::new();
+        for slot in self.days.iter().flat_map(|day| &day.slots) {
+            if !meals.contains(&slot.name.as_str()) {
+                meals.push(&slot.name);
+            }
         }
 
-        // If no markers, return one list with all dishes
-        if marker_positions.is_empty() {
-            let list: IngredientList = all_dishes
-                .iter()
-                .map(|dish| IngredientList::from(dish.shopping_list()))
-                .sum();
-            return vec![list];
+        let columns = if meals.is_empty() {
+            vec!["Personen"]
+        } else {
+            meals.clone()
+        };
+        let mut output = format!(
+            "| Tag | {} |\n| --- |{}\n",
+            columns.join(" | "),
+            " ---: |".repeat(columns.len())
+        );
+        for day in &self.days {
+            let counts: Vec<String> = if meals.is_empty() {
+                vec![day.people.to_string()]
+            } else {
+                meals
+                    .iter()
+                    .map(|meal| {
+                        day.slots
+                            .iter()
+                            .find(|slot| slot.name == *meal)
+                            .map(|slot| slot.people.unwrap_or(day.people).to_string())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            };
+            output.push_str(&format!("| {} | {} |\n", day.label(), counts.join(" | ")));
         }
 
-        // Generate shopping lists for dishes between consecutive markers
-        let mut lists = Vec::new();
-        let mut start_idx = 0;
+        output
+    }
+
+    /// Reste meals with the dishes whose surplus feeds them, as a markdown table.
+    pub(crate) fn leftovers(&self) -> String {
+        let meals: Vec<RestMeal> = self
+            .days
+            .iter()
+            .flat_map(|day| day.leftovers.iter().cloned())
+            .collect();
+        leftovers::as_markdown(&meals)
+    }
+
+    /// One page per dish for the PDF export, headed by the day's date.
+    pub(crate) fn dish_pages(&self) -> Vec<Page> {
+        let mut pages = Vec::new();
 
-        for &marker_idx in &marker_positions {
-            if marker_idx > start_idx {
-                let list: IngredientList = all_dishes[start_idx..marker_idx]
-                    .iter()
-                    .map(|dish| IngredientList::from(dish.shopping_list()))
-                    .sum();
-                lists.push(list);
+        for day in &self.days {
+            for dish in &day.dishes {
+                pages.push(Page {
+                    header: day.label(),
+                    markdown: dish.as_markdown(),
+                });
             }
-            start_idx = marker_idx;
         }
 
-        // Add remaining dishes after the last marker
-        if start_idx < all_dishes.len() {
-            let list: IngredientList = all_dishes[start_idx..]
-                .iter()
-                .map(|dish| IngredientList::from(dish.shopping_list()))
-                .sum();
-            lists.push(list);
+        pages
+    }
+
+    /// Generate one shopping trip per shopping marker across all days.
+    /// Trips span multiple days until the next shopping marker is encountered,
+    /// dishes before the first marker form an unnamed trip.
+    pub(crate) fn shopping_lists(&self) -> Vec<ShoppingTrip> {
+        let mut trips = vec![ShoppingTrip::new(&Marker::default(), self.start)];
+
+        for day in &self.days {
+            let mut markers = day.shopping_days.iter().peekable();
+            for (i, dish) in day.dishes.iter().enumerate() {
+                while let Some(marker) = markers.next_if(|marker| marker.index <= i) {
+                    trips.push(ShoppingTrip::new(marker, day.date));
+                }
+                if let Some(trip) = trips.last_mut() {
+                    trip.add(dish, day.date);
+                }
+            }
+            for marker in markers {
+                trips.push(ShoppingTrip::new(marker, day.date));
+            }
         }
 
-        lists
+        trip::finish(trips)
     }
 }
 
@@ -137,37 +291,47 @@ impl Plan for WeekPlan {
         self.days.iter().map(|day| day.shopping_list()).sum()
     }
 
-    fn from_file(path: &Path, cookbook: &CookBook) -> Self {
+    fn from_file(path: &Path, cookbook: &CookBook) -> Result<Self> {
         let content = fs::read_to_string(path)
-            .with_context(|| format!("Failed to read plan file: {}", path.display()))
-            .unwrap();
+            .with_context(|| format!("Failed to read plan file: {}", path.display()))?;
+        if table::is_table(&content) {
+            return Ok(Self::from_table(&content, cookbook));
+        }
 
         let mut parser = Parser::new();
         parser
             .set_language(&tree_sitter_menu::LANGUAGE.into())
-            .expect("Error loading menu parser");
+            .context("Error loading menu parser")?;
 
-        let tree = parser.parse(&content, None).expect("Failed to parse");
+        let tree = parser
+            .parse(&content, None)
+            .context("Failed to parse plan file")?;
         let root = tree.root_node();
 
-        eprintln!("Root node kind: {}", root.kind());
-        eprintln!("Root has error: {}", root.has_error());
-        eprintln!("Tree: {}", root.to_sexp());
-
         let mut cursor = root.walk();
 
         let mut people = 1;
         let mut start_date = chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        let mut header_at = Position { line: 1, column: 1 };
+        let mut schedule = None;
         let mut days = Vec::new();
-
+        let mut ctx = PlanContext {
+            content: &content,
+            cookbook,
+            groups: Groups::default(),
+            leftovers: Leftovers::default(),
+            diagnostics: Report::default(),
+        };
         for child in root.children(&mut cursor) {
-            eprintln!("Child kind: {}", child.kind());
             match child.kind() {
                 "persons_line" => {
+                    header_at = child.start_position().into();
                     if let Some(count_node) = child.child_by_field_name("count") {
                         let count_str = content[count_node.byte_range()].trim();
                         people = count_str.parse().unwrap_or(1);
-                        eprintln!("Parsed people: {}", people);
+                    }
+                    if let Some(groups_node) = child.child_by_field_name("groups") {
+                        ctx.groups = read_groups(&groups_node, &mut ctx);
                     }
                 }
                 "starttag_line" => {
@@ -177,48 +341,243 @@ impl Plan for WeekPlan {
                             .unwrap_or_else(|_| {
                                 chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()
                             });
-                        eprintln!("Parsed date: {}", start_date);
                     }
+                    schedule = attendance(&mut ctx, start_date, &[], header_at);
                 }
                 "day_line" => {
-                    let day = parse_day_line(&child, &content, cookbook, people);
-                    eprintln!("Parsed day with {} dishes", day.dishes.len());
+                    let date = start_date + chrono::Days::new(days.len() as u64);
+                    let people = schedule
+                        .as_ref()
+                        .map_or(people, |schedule| schedule.people(date, None));
+                    let day = parse_day_line(&child, &mut ctx, people, date);
+                    if day.people == 0 {
+                        let at = child.start_position().into();
+                        ctx.diagnostics
+                            .push(at, Issue::NobodyPresent { meal: day.label() });
+                    }
                     days.push(day);
                 }
                 _ => {}
             }
         }
 
-        eprintln!("Total days: {}", days.len());
-        eprintln!(
-            "Total dishes across all days: {}",
-            days.iter().map(|d| d.dishes.len()).sum::<usize>()
-        );
+        if let Some(schedule) = &schedule {
+            check_header(&mut ctx, schedule, &days, people, header_at);
+        }
+        report_uneaten(&mut ctx);
+
+        Ok(Self {
+            start: start_date,
+            days,
+            diagnostics: ctx.diagnostics,
+        })
+    }
+}
+
+impl WeekPlan {
+    /// Build the plan from the table format with meal rows and day columns.
+    ///
+    /// Dishes use the head count of their cell, meal row, day column, the
+    /// attendance or the plan, whichever is given first. Plain text entries name a dish if the
+    /// cookbook has one, otherwise they are kept as notes.
+    fn from_table(content: &str, cookbook: &CookBook) -> Self {
+        let plan = table::parse(content);
+        let people = plan.people.unwrap_or(1);
+        let start = plan
+            .start
+            .unwrap_or_else(|| NaiveDate::from_ymd_opt(2026, 1, 1).unwrap());
+        let mut ctx = PlanContext {
+            content,
+            cookbook,
+            groups: Groups::default(),
+            leftovers: Leftovers::default(),
+            diagnostics: Report::default(),
+        };
+        if let Some((list, at)) = &plan.groups {
+            ctx.groups = parse_groups(list, *at, &mut ctx);
+        }
+        let meals: Vec<String> = plan
+            .slots
+            .iter()
+            .map(|row| row.header.name.clone())
+            .collect();
+        let schedule = attendance(&mut ctx, start, &meals, Position { line: 1, column: 1 });
+
+        let mut days = Vec::new();
+        for (column, header) in plan.days.iter().enumerate() {
+            let date = start + chrono::Days::new(column as u64);
+            check_weekday(&header.name, date, header.at, &mut ctx);
+            let mut day = Day {
+                name: header.name.clone(),
+                date,
+                people: header
+                    .people
+                    .or_else(|| Some(schedule.as_ref()?.people(date, None)))
+                    .unwrap_or(people),
+                ..Default::default()
+            };
+            if day.people == 0 {
+                ctx.diagnostics
+                    .push(header.at, Issue::NobodyPresent { meal: day.label() });
+            }
+
+            for (meal, row) in plan.slots.iter().enumerate() {
+                let slot_people = row
+                    .header
+                    .people
+                    .or(header.people)
+                    .or_else(|| Some(schedule.as_ref()?.people(date, Some(meal))));
+                let fallback = slot_people.unwrap_or(people);
+                let begin = day.dishes.len();
+                let mut notes = Vec::new();
+                let mut fixed = Vec::new();
+                let mut rest = false;
+
+                for entry in row.cells.get(column).into_iter().flatten() {
+                    let dish = match entry {
+                        Entry::Dish {
+                            reference,
+                            people,
+                            at,
+                        } => load_dish(&mut ctx, reference, people.unwrap_or(fallback), *at)
+                            .inspect(|dish| fixed.push(people.is_some() || has_options(dish))),
+                        Entry::Text { text, at } if cookbook.get(text).is_some() => {
+                            load_dish(&mut ctx, text, fallback, *at).inspect(|_| fixed.push(false))
+                        }
+                        Entry::Text { text, .. } if text.eq_ignore_ascii_case("Reste") => {
+                            rest = true;
+                            None
+                        }
+                        Entry::Text { text, .. } => {
+                            notes.push(text.clone());
+                            None
+                        }
+                        Entry::Marker { text, .. } => {
+                            let marker = Marker::parse(text, day.dishes.len(), date);
+                            day.shopping_days.push(marker);
+                            None
+                        }
+                    };
+                    day.dishes.extend(dish);
+                }
+
+                let meal = format!("{}, {}", day.label(), row.header.name);
+                if fallback == 0 && day.people != 0 {
+                    let issue = Issue::NobodyPresent { meal: meal.clone() };
+                    ctx.diagnostics.push(row.header.at, issue);
+                }
+                serve_groups(
+                    &mut ctx,
+                    &mut day.dishes[begin..],
+                    &fixed,
+                    fallback,
+                    &meal,
+                    row.header.at,
+                );
+                if rest {
+                    let served = serve_leftovers(&mut ctx, meal, fallback, row.header.at);
+                    day.leftovers.push(served);
+                }
+                ctx.leftovers
+                    .cook(&day.dishes[begin..], date, row.header.at);
+                day.slots.push(Slot {
+                    name: row.header.name.clone(),
+                    people: slot_people,
+                    dishes: begin..day.dishes.len(),
+                    notes,
+                });
+            }
+            days.push(day);
+        }
+        if let (Some(schedule), Some(people)) = (&schedule, plan.people) {
+            check_header(
+                &mut ctx,
+                schedule,
+                &days,
+                people,
+                Position { line: 1, column: 1 },
+            );
+        }
+        report_uneaten(&mut ctx);
 
         Self {
-            _start: start_date,
+            start,
             days,
+            diagnostics: ctx.diagnostics,
+        }
+    }
+}
+
+/// Markdown of a meal with its notes and dishes, empty for meals without entries.
+///
+/// Dish headings move one level down below the meal heading.
+fn slot_as_markdown(slot: &Slot, dishes: &[Dish]) -> String {
+    if slot.dishes.is_empty() && slot.notes.is_empty() {
+        return String::new();
+    }
+
+    let mut output = match slot.people {
+        Some(people) => format!("## {} ({} Personen)\n\n", slot.name, people),
+        None => format!("## {}\n\n", slot.name),
+    };
+    for note in &slot.notes {
+        output.push_str(&format!("- {note}\n"));
+    }
+    if !slot.notes.is_empty() {
+        output.push('\n');
+    }
+    for dish in &dishes[slot.dishes.clone()] {
+        for line in dish.as_markdown().lines() {
+            if line.starts_with('#') {
+                output.push('#');
+            }
+            output.push_str(line);
+            output.push('\n');
         }
+        output.push('\n');
     }
+
+    output
+}
+
+/// Shared state while walking the plan syntax tree.
+struct PlanContext<'a> {
+    /// Source text of the plan.
+    content: &'a str,
+    cookbook: &'a CookBook,
+    /// Diet groups among the participants.
+    groups: Groups,
+    /// Planned surplus not eaten by a Reste meal yet.
+    leftovers: Leftovers,
+    /// Collected problems.
+    diagnostics: Report,
 }
 
 fn parse_day_line(
     node: &tree_sitter::Node,
-    content: &str,
-    cookbook: &CookBook,
+    ctx: &mut PlanContext,
     default_people: usize,
+    date: NaiveDate,
 ) -> Day {
+    let content = ctx.content;
+    let mut name = String::new();
     let mut day_people = None;
     let mut dishes = Vec::new();
     let mut shopping_days = Vec::new();
+    let mut fixed = Vec::new();
+    let mut rest = false;
+    let mut at = node.start_position().into();
 
     let mut cursor = node.walk();
 
-    eprintln!("  Day line children:");
     for child in node.children(&mut cursor) {
-        eprintln!("    - kind: {}", child.kind());
         match child.kind() {
             "day_with_count" => {
+                if let Some(name_node) = child.child_by_field_name("name") {
+                    name = content[name_node.byte_range()].trim().to_string();
+                    at = name_node.start_position().into();
+                    check_weekday(&name, date, at, ctx);
+                }
                 if let Some(count_node) = child.child_by_field_name("count") {
                     let count_str = content[count_node.byte_range()].trim();
                     let count_str = count_str.trim_start_matches('(').trim_end_matches(')');
@@ -226,97 +585,122 @@ fn parse_day_line(
                 }
             }
             "menu" => {
-                eprintln!("    Found menu node");
-                parse_menu(
+                rest = parse_menu(
                     &child,
-                    content,
-                    cookbook,
+                    ctx,
                     &mut dishes,
                     &mut shopping_days,
-                    default_people,
-                    day_people,
+                    &mut fixed,
+                    day_people.unwrap_or(default_people),
+                    date,
                 );
             }
             _ => {}
         }
     }
 
-    Day {
+    let people = day_people.unwrap_or(default_people);
+    let mut day = Day {
+        name,
+        date,
+        people,
         dishes,
         shopping_days,
+        ..Default::default()
+    };
+    let meal = day.label();
+    serve_groups(ctx, &mut day.dishes, &fixed, people, &meal, at);
+    if rest {
+        day.leftovers.push(serve_leftovers(ctx, meal, people, at));
+    }
+    ctx.leftovers.cook(&day.dishes, date, at);
+    day
+}
+
+/// Weekday of a German day name.
+pub(crate) fn parse_weekday(name: &str) -> Option<Weekday> {
+    [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ]
+    .into_iter()
+    .find(|weekday| weekday_name(*weekday).eq_ignore_ascii_case(name))
+}
+
+/// Warn if the written weekday does not match the date derived from the start day.
+fn check_weekday(name: &str, date: NaiveDate, at: Position, ctx: &mut PlanContext) {
+    if let Some(written) = parse_weekday(name) {
+        if written != date.weekday() {
+            ctx.diagnostics.push(
+                at,
+                Issue::WeekdayMismatch {
+                    day: name.to_string(),
+                    date,
+                },
+            );
+        }
     }
 }
 
+/// Read the dishes of a menu, returns whether it is a Reste day.
 fn parse_menu(
     node: &tree_sitter::Node,
-    content: &str,
-    cookbook: &CookBook,
+    ctx: &mut PlanContext,
     dishes: &mut Vec<Dish>,
-    shopping_days: &mut Vec<usize>,
-    default_people: usize,
-    day_people: Option<usize>,
-) {
+    shopping_days: &mut Vec<Marker>,
+    fixed: &mut Vec<bool>,
+    people: usize,
+    date: NaiveDate,
+) -> bool {
     let mut cursor = node.walk();
 
-    eprintln!("      Menu children:");
     for child in node.children(&mut cursor) {
-        eprintln!("        - kind: {}", child.kind());
         match child.kind() {
             "rest_day" => {
-                return;
+                return true;
             }
             "menu_items" => {
-                eprintln!("        Found menu_items");
                 let mut items_cursor = child.walk();
                 for item in child.children(&mut items_cursor) {
-                    eprintln!("          Item kind: {}", item.kind());
                     if item.kind() == "menu_item" {
-                        parse_menu_item(
-                            &item,
-                            content,
-                            cookbook,
-                            dishes,
-                            shopping_days,
-                            default_people,
-                            day_people,
-                        );
+                        parse_menu_item(&item, ctx, dishes, shopping_days, fixed, people, date);
                     }
                 }
             }
             _ => {}
         }
     }
+    false
 }
 
 fn parse_menu_item(
     node: &tree_sitter::Node,
-    content: &str,
-    cookbook: &CookBook,
+    ctx: &mut PlanContext,
     dishes: &mut Vec<Dish>,
-    shopping_days: &mut Vec<usize>,
-    default_people: usize,
-    day_people: Option<usize>,
+    shopping_days: &mut Vec<Marker>,
+    fixed: &mut Vec<bool>,
+    day_people: usize,
+    date: NaiveDate,
 ) {
+    let content = ctx.content;
     // The menu_item node directly contains either dish_with_count or shopping_marker
     // Get the first child which should be the actual content
     // let mut _cursor = node.walk();
 
     if let Some(child) = node.child(0) {
-        eprintln!("          Menu item child kind: {}", child.kind());
         match child.kind() {
             "dish_with_count" => {
                 if let Some(dish_node) = child.child_by_field_name("dish") {
-                    eprintln!("            Found dish node: {}", dish_node.kind());
-
                     // Get the full dish text (e.g., "[[Dish Name]]")
                     let dish_text = content[dish_node.byte_range()].trim();
-                    eprintln!("            Dish text: {}", dish_text);
 
                     // Strip the [[ and ]] brackets to get the dish name
                     if dish_text.starts_with("[[") && dish_text.ends_with("]]") {
-                        let dish_name = &dish_text[2..dish_text.len() - 2];
-                        eprintln!("            Dish name: {}", dish_name);
-
                         // Extract multiplier if present
                         let dish_people = child.child_by_field_name("count").map(|count_node| {
                             let count_str = content[count_node.byte_range()].trim();
@@ -324,48 +708,372 @@ fn parse_menu_item(
                             let count_str = count_str.trim_start_matches('(').trim_end_matches(')');
                             count_str.parse::<usize>().unwrap_or(1)
                         });
-
-                        // Look up dish in cookbook
-                        if let Some(dish_path) = cookbook.get(dish_name) {
-                            eprintln!("            Found in cookbook: {:?}", dish_path);
-
-                            // use the proper amount of people!
-                            let people =
-                                dish_people.unwrap_or(day_people.unwrap_or(default_people));
-                            match Dish::from_file(dish_path, dish_name, people) {
-                                Ok(dish) => {
-                                    eprintln!(
-                                        "            Loaded dish with {} ingredients",
-                                        dish.ingredients.len()
-                                    );
-                                    dishes.push(dish);
-                                }
-                                Err(e) => {
-                                    eprintln!("            Error loading dish: {}", e);
-                                }
-                            }
-                        } else {
-                            eprintln!("            NOT found in cookbook");
+                        let people = dish_people.unwrap_or(day_people);
+                        let reference = &dish_text[2..dish_text.len() - 2];
+                        let at = dish_node.start_position().into();
+                        if let Some(dish) = load_dish(ctx, reference, people, at) {
+                            fixed.push(dish_people.is_some() || has_options(&dish));
+                            dishes.push(dish);
                         }
-                    } else {
-                        eprintln!("            Invalid dish format (missing brackets)");
                     }
-                } else {
-                    eprintln!("            No dish node found");
                 }
             }
             "shopping_marker" => {
-                shopping_days.push(dishes.len());
+                let text = &content[child.byte_range()];
+                shopping_days.push(Marker::parse(text, dishes.len(), date));
             }
             _ => {}
         }
     }
 }
 
+/// Load a referenced dish like `Gulasch|vegan 5` for the given head count.
+///
+/// Missing and broken dishes are reported and skipped.
+fn load_dish(ctx: &mut PlanContext, reference: &str, people: usize, at: Position) -> Option<Dish> {
+    let (dish_name, options) = parse_dish_options(reference);
+
+    // Look up dish in cookbook
+    let Some(dish_path) = ctx.cookbook.get(dish_name) else {
+        ctx.diagnostics.push(
+            at,
+            Issue::MissingDish {
+                dish: dish_name.to_string(),
+            },
+        );
+        return None;
+    };
+
+    let mut dish =
+        match Dish::from_file_with_units(dish_path, dish_name, people, &ctx.cookbook.units) {
+            Ok(dish) => dish,
+            Err(e) => {
+                ctx.diagnostics.push(
+                    at,
+                    Issue::DishParse {
+                        dish: dish_name.to_string(),
+                        error: e.to_string(),
+                    },
+                );
+                return None;
+            }
+        };
+    if let Some(unit) = &options.unit {
+        // A dish without a batch yield has no units to ask for.
+        let issue = match &dish.batch {
+            Some(_) => dish.request_pieces(people, unit).err(),
+            None => Some(Issue::UnknownDishOption {
+                dish: dish_name.to_string(),
+                option: unit.clone(),
+            }),
+        };
+        if let Some(issue) = issue {
+            ctx.diagnostics.push(at, issue);
+        }
+    }
+    for option in &options.unknown {
+        ctx.diagnostics.push(
+            at,
+            Issue::UnknownDishOption {
+                dish: dish_name.to_string(),
+                option: option.clone(),
+            },
+        );
+    }
+    dish.resolve_ranges(ctx.cookbook.ranges);
+    dish.expand_sub_dishes(ctx.cookbook);
+    dish.resolve_scaling(&ctx.cookbook.scaling, &ctx.cookbook.aliases);
+    for issue in dish.issues.drain(..) {
+        ctx.diagnostics.push(at, issue);
+    }
+    dish.surplus = options.surplus;
+    if let Some(vegan_people) = options.vegan {
+        let people = dish.people.unwrap_or(people);
+        dish.vegan = Some(vegan_portions(
+            &dish,
+            vegan_people.unwrap_or(people),
+            ctx.cookbook,
+        ));
+    }
+    for issue in estimate(&mut dish, ctx.cookbook) {
+        ctx.diagnostics.push(at, issue);
+    }
+    Some(dish)
+}
+
+/// Attach nutrition, allergens and costs to a loaded dish, if the cookbook has the data.
+///
+/// Returns the ingredients missing in the tables as issues.
+fn estimate(dish: &mut Dish, cookbook: &CookBook) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    if let Some(table) = &cookbook.nutrients {
+        // The surplus is eaten on a Reste day, not by today's participants.
+        let people = dish.portions().round() as usize;
+        let nutrition = table.nutrition(&dish.shopping_list(), people, &cookbook.aliases);
+        if !nutrition.missing.is_empty() {
+            issues.push(Issue::MissingNutrients {
+                dish: dish.name.clone(),
+                ingredients: nutrition.missing.clone(),
+            });
+        }
+        dish.nutrition = Some(nutrition);
+    }
+
+    if let Some(table) = &cookbook.allergens {
+        let labels = table.labels(&dish.shopping_list(), &cookbook.aliases);
+        if !labels.missing.is_empty() {
+            issues.push(Issue::MissingAllergens {
+                dish: dish.name.clone(),
+                ingredients: labels.missing.clone(),
+            });
+        }
+        dish.labels = Some(labels);
+    }
+
+    if let Some(prices) = &cookbook.prices {
+        dish.costs = Some(prices.costs(&dish.shopping_list(), &cookbook.aliases));
+    }
+
+    issues
+}
+
+/// Give the dishes of a meal the head counts of the participant groups and
+/// warn about groups without a fitting dish.
+fn serve_groups(
+    ctx: &mut PlanContext,
+    dishes: &mut [Dish],
+    fixed: &[bool],
+    people: usize,
+    meal: &str,
+    at: Position,
+) {
+    if ctx.groups.is_empty() || dishes.is_empty() {
+        return;
+    }
+
+    let counts = ctx.groups.assign(dishes, fixed, people);
+    for (dish, count) in dishes.iter_mut().zip(counts) {
+        if let Some(count) = count {
+            if let Some(vegan) = &mut dish.vegan {
+                // A share of all portions follows the head count, an explicit
+                // one cannot exceed it.
+                if Some(vegan.people) == dish.people || vegan.people > count {
+                    vegan.people = count;
+                }
+            }
+            dish.people = Some(count);
+            // Missing table entries were already reported when loading.
+            estimate(dish, ctx.cookbook);
+        }
+    }
+
+    for (diet, people) in ctx.groups.unserved(dishes) {
+        ctx.diagnostics.push(
+            at,
+            Issue::UnservedGroup {
+                meal: meal.to_string(),
+                diet: diet.name().to_string(),
+                people,
+            },
+        );
+    }
+}
+
+/// Feed a Reste meal from the planned surplus, warns if it does not cover everyone.
+fn serve_leftovers(ctx: &mut PlanContext, meal: String, people: usize, at: Position) -> RestMeal {
+    let served = ctx.leftovers.serve(meal, people);
+    if !served.covered() {
+        ctx.diagnostics.push(
+            at,
+            Issue::UncoveredRestMeal {
+                meal: served.meal.clone(),
+                portions: served.portions().floor() as usize,
+                people,
+            },
+        );
+    }
+    served
+}
+
+/// Warn about planned surplus that no Reste meal eats.
+fn report_uneaten(ctx: &mut PlanContext) {
+    for surplus in ctx.leftovers.uneaten() {
+        ctx.diagnostics.push(
+            surplus.at,
+            Issue::UneatenSurplus {
+                dish: surplus.dish.clone(),
+                portions: surplus.portions.round() as usize,
+            },
+        );
+    }
+}
+
+/// Place the attendance cohorts in the plan, if the cookbook has them.
+fn attendance(
+    ctx: &mut PlanContext,
+    start: NaiveDate,
+    meals: &[String],
+    at: Position,
+) -> Option<Schedule> {
+    let (schedule, issues) = ctx.cookbook.attendance.as_ref()?.schedule(start, meals);
+    for issue in issues {
+        ctx.diagnostics.push(at, issue);
+    }
+    Some(schedule)
+}
+
+/// Warn if the attendance peaks at a different head count than the plan header.
+fn check_header(
+    ctx: &mut PlanContext,
+    schedule: &Schedule,
+    days: &[Day],
+    people: usize,
+    at: Position,
+) {
+    let Some(peak) = days.iter().map(|day| schedule.people(day.date, None)).max() else {
+        return;
+    };
+    if peak != people {
+        ctx.diagnostics.push(
+            at,
+            Issue::AttendanceMismatch {
+                people,
+                attendance: peak,
+            },
+        );
+    }
+}
+
+/// Read the participant groups, unknown diets are reported and skipped.
+fn parse_groups(list: &str, at: Position, ctx: &mut PlanContext) -> Groups {
+    let (groups, unknown) = Groups::parse(list);
+    for diet in unknown {
+        ctx.diagnostics
+            .push(at, Issue::UnknownDiet { dish: None, diet });
+    }
+    groups
+}
+
+/// Read the participant groups of a header like `Personen: 43 (vegan 12)`.
+///
+/// Groups with an unknown diet or without a count are reported and skipped.
+fn read_groups(node: &tree_sitter::Node, ctx: &mut PlanContext) -> Groups {
+    let content = ctx.content;
+    let mut groups = Vec::new();
+    let mut cursor = node.walk();
+
+    for group in node.children(&mut cursor) {
+        if group.kind() != "group" {
+            continue;
+        }
+        let diet = group
+            .child_by_field_name("diet")
+            .and_then(|diet| allergens::Diet::parse(&content[diet.byte_range()]));
+        let count = group
+            .child_by_field_name("count")
+            .and_then(|count| content[count.byte_range()].parse().ok());
+        match (diet, count) {
+            (Some(diet), Some(count)) => groups.push((diet, count)),
+            _ => ctx.diagnostics.push(
+                group.start_position().into(),
+                Issue::UnknownDiet {
+                    dish: None,
+                    diet: content[group.byte_range()].trim().to_string(),
+                },
+            ),
+        }
+    }
+
+    Groups(groups)
+}
+
+/// Options of a dish reference, written after `|` separators.
+#[derive(Debug, Default, PartialEq)]
+struct DishOptions {
+    /// Requested vegan portions, `None` inside for all portions.
+    vegan: Option<Option<usize>>,
+    /// Unit the count is meant in, e.g. "Stück", if not persons.
+    unit: Option<String>,
+    /// Share cooked on top for a Reste meal, e.g. 0.2 for `+20%`.
+    surplus: Option<f32>,
+    /// Options that are neither of the above, e.g. a second unit or `vegan x`.
+    unknown: Vec<String>,
+}
+
+/// Split a dish reference like `Gulasch|vegan 5` into the dish name and its
+/// options. `Gulasch|vegan` makes all portions vegan, `Muffins|Stück` reads
+/// the count as pieces and `Muffins|Personen` explicitly as persons.
+/// `Gulasch|+20%` cooks a fifth more for a later Reste meal. Words after the
+/// first unit are kept as unknown and reported when the dish is loaded.
+fn parse_dish_options(text: &str) -> (&str, DishOptions) {
+    let mut parts = text.split('|');
+    let name = parts.next().unwrap_or_default().trim();
+    let mut options = DishOptions::default();
+
+    for option in parts.map(str::trim) {
+        let share = option
+            .strip_prefix('+')
+            .and_then(|share| share.strip_suffix('%'))
+            .and_then(|share| share.trim().replace(',', ".").parse::<f32>().ok());
+        if let Some(share) = share {
+            options.surplus = Some(share / 100.0);
+        } else if let Some(count) = option.strip_prefix("vegan") {
+            match count.trim() {
+                "" => options.vegan = Some(None),
+                count => match count.parse() {
+                    Ok(count) => options.vegan = Some(Some(count)),
+                    Err(_) => options.unknown.push(option.to_string()),
+                },
+            }
+        } else if matches!(option, "Personen" | "Portionen") {
+            options.unit = None;
+        } else if option.is_empty() {
+            continue;
+        } else if options.unit.is_none() {
+            options.unit = Some(option.to_string());
+        } else {
+            options.unknown.push(option.to_string());
+        }
+    }
+
+    (name, options)
+}
+
+/// Whether the options of a dish reference set its portions, e.g. `Muffins|Stück`.
+fn has_options(dish: &Dish) -> bool {
+    dish.vegan.is_some() || dish.pieces.is_some()
+}
+
+/// Look up the vegan substitutes for all ingredients of a dish.
+fn vegan_portions(dish: &Dish, people: usize, cookbook: &CookBook) -> VeganPortions {
+    let substitutes = dish
+        .base_ingredients()
+        .iter()
+        .filter_map(|ingredient| {
+            cookbook
+                .substitutions
+                .get(&ingredient.name)
+                .map(|substitute| (ingredient.name.clone(), substitute.to_string()))
+        })
+        .collect();
+
+    VeganPortions {
+        people,
+        substitutes,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::Ingredient;
+    use crate::{
+        allergens::{AllergenTable, Diet},
+        attendance::Attendance,
+        nutrition::NutrientTable,
+        prices::Prices,
+        types::Ingredient,
+    };
     use std::io::Write;
     use tempfile::{NamedTempFile, TempDir};
 
@@ -376,6 +1084,8 @@ mod tests {
             measure: measure.to_string(),
             name: name.to_string(),
             dish: dish.to_string(),
+            packed: None,
+            quantity: None,
         }
     }
 
@@ -436,6 +1146,7 @@ mod tests {
         let day = Day {
             dishes: vec![dish],
             shopping_days: vec![],
+            ..Default::default()
         };
 
         let shopping_list = day.shopping_list();
@@ -475,6 +1186,7 @@ mod tests {
         let day = Day {
             dishes: vec![dish1, dish2],
             shopping_days: vec![],
+            ..Default::default()
         };
 
         let shopping_list = day.shopping_list();
@@ -486,6 +1198,7 @@ mod tests {
         let day = Day {
             dishes: vec![],
             shopping_days: vec![],
+            ..Default::default()
         };
         let shopping_list = day.shopping_list();
         assert_eq!(shopping_list.0.len(), 0);
@@ -508,15 +1221,18 @@ mod tests {
         let day1 = Day {
             dishes: vec![dish1],
             shopping_days: vec![],
+            ..Default::default()
         };
         let day2 = Day {
             dishes: vec![dish2],
             shopping_days: vec![],
+            ..Default::default()
         };
 
         let weekplan = WeekPlan {
-            _start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             days: vec![day1, day2],
+            diagnostics: Report::default(),
         };
 
         let shopping_list = weekplan.shopping_list();
@@ -549,7 +1265,7 @@ Montag: [[Test Dish]]
 
         let cookbook = CookBook::from_file(temp_dir.path());
 
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         // Should have parsed the file successfully
         assert_eq!(weekplan.days.len(), 1);
@@ -580,10 +1296,10 @@ Montag: [[Test Dish]]
         std::fs::write(&dish_path, dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(
-            weekplan._start,
+            weekplan.start,
             chrono::NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()
         );
     }
@@ -609,7 +1325,7 @@ Montag: [[Dish1]], [[Dish2]]
         std::fs::write(temp_dir.path().join("Dish2.txt"), dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(weekplan.days.len(), 1);
         assert_eq!(weekplan.days[0].dishes.len(), 2);
@@ -639,7 +1355,7 @@ Mittwoch: [[Dish3]]
         std::fs::write(temp_dir.path().join("Dish3.txt"), dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(weekplan.days.len(), 3);
         assert_eq!(weekplan.days[0].dishes.len(), 1);
@@ -668,13 +1384,73 @@ Dienstag: Reste
         std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(weekplan.days.len(), 2);
         assert_eq!(weekplan.days[0].dishes.len(), 1);
         assert_eq!(weekplan.days[1].dishes.len(), 0); // Rest day = no dishes
     }
 
+    #[test]
+    fn test_weekplan_leftovers() {
+        let dish_content = "2 Personen\n\n## Zutaten\n- 100 g Butter\n";
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path());
+
+        let menu_file = create_test_dish_file(
+            "Personen: 10\nStarttag: 2026-01-01\n\
+             Donnerstag: [[Dish1|+20%]]\n\
+             Freitag: [[Dish1|+30%]]\n\
+             Samstag (4): Reste\n\
+             Sonntag: Reste\n\
+             Montag: [[Dish1|+10%]]\n",
+        );
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        // The surplus is bought with the dish.
+        assert!((weekplan.days[0].shopping_list().0[0].amount - 600.0).abs() < 1e-3);
+        assert_eq!(weekplan.days[0].dishes[0].people, Some(10));
+
+        let saturday = &weekplan.days[2].leftovers[0];
+        assert!(saturday.covered());
+        assert_eq!(saturday.fed.len(), 2);
+        assert_eq!(
+            weekplan.leftovers(),
+            "| Reste | Personen | Portionen | Gedeckt | Aus |\n\
+             | --- | ---: | ---: | --- | --- |\n\
+             | Samstag, 03.01.2026 | 4 | 4 | ja | Dish1 vom 01.01. (2), Dish1 vom 02.01. (2) |\n\
+             | Sonntag, 04.01.2026 | 10 | 1 | nein | Dish1 vom 02.01. (1) |\n"
+        );
+        let issues: Vec<String> = weekplan
+            .diagnostics
+            .0
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                "6:1: Sonntag, 04.01.2026 has leftovers for 1 of 10 participants",
+                "7:1: dish [[Dish1]] has 1 surplus portions that no Reste meal eats",
+            ]
+        );
+
+        let table_file = create_test_dish_file(
+            "Personen: 10\nStarttag: 2026-01-01\n\n\
+             | | Donnerstag |\n\
+             | --- | --- |\n\
+             | **Mittagessen** | [[Dish1\\|+50%]] |\n\
+             | **Abendessen** (5) | Reste |\n",
+        );
+        let weekplan = WeekPlan::from_file(table_file.path(), &cookbook).unwrap();
+        let evening = &weekplan.days[0].leftovers[0];
+        assert_eq!(evening.meal, "Donnerstag, 01.01.2026, Abendessen");
+        assert!(evening.covered());
+        assert!(weekplan.days[0].slots[1].notes.is_empty());
+        assert!(weekplan.diagnostics.is_empty());
+    }
+
     #[test]
     fn test_weekplan_from_file_dish_with_count() {
         let menu_content = r#"Personen: 2
@@ -695,7 +1471,7 @@ Montag: [[Dish1]](4)
         std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(weekplan.days.len(), 1);
         assert_eq!(weekplan.days[0].dishes.len(), 1);
@@ -724,7 +1500,7 @@ Montag(4): [[Dish1]]
         std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(weekplan.days.len(), 1);
         assert_eq!(weekplan.days[0].dishes.len(), 1);
@@ -753,7 +1529,7 @@ Montag(4): [[Dish1]](2)
         std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
 
         let cookbook = CookBook::from_file(temp_dir.path());
-        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook);
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
 
         assert_eq!(weekplan.days.len(), 1);
         assert_eq!(weekplan.days[0].dishes.len(), 1);
@@ -786,25 +1562,35 @@ Montag(4): [[Dish1]](2)
         let day1 = Day {
             dishes: vec![dish1, dish2],
             shopping_days: vec![],
+            ..Default::default()
         };
         let day2 = Day {
             dishes: vec![dish3, dish4],
-            shopping_days: vec![1], // Marker after Dish3
+            shopping_days: vec![Marker {
+                index: 1, // Marker after Dish3
+                label: "Markt Fr".to_string(),
+                date: chrono::NaiveDate::from_ymd_opt(2026, 1, 2),
+            }],
+            ..Default::default()
         };
 
         let weekplan = WeekPlan {
-            _start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             days: vec![day1, day2],
+            diagnostics: Report::default(),
         };
 
         let lists = weekplan.shopping_lists();
         assert_eq!(lists.len(), 2);
 
         // First list should contain Dish1, Dish2, Dish3 (spans day 1 and part of day 2)
-        assert_eq!(lists[0].0.len(), 3);
+        assert_eq!(lists[0].list.0.len(), 3);
+        assert_eq!(lists[0].label, "Einkauf 1");
 
         // Second list should contain Dish4 (after the marker)
-        assert_eq!(lists[1].0.len(), 1);
+        assert_eq!(lists[1].list.0.len(), 1);
+        assert_eq!(lists[1].label, "Markt Fr");
+        assert!(lists[1].explicit);
     }
 
     #[test]
@@ -826,21 +1612,25 @@ Montag(4): [[Dish1]](2)
         let day1 = Day {
             dishes: vec![dish1],
             shopping_days: vec![],
+            ..Default::default()
         };
         let day2 = Day {
             dishes: vec![dish2],
             shopping_days: vec![],
+            ..Default::default()
         };
 
         let weekplan = WeekPlan {
-            _start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             days: vec![day1, day2],
+            diagnostics: Report::default(),
         };
 
         let lists = weekplan.shopping_lists();
         assert_eq!(lists.len(), 1);
         // All dishes in one list
-        assert_eq!(lists[0].0.len(), 2);
+        assert_eq!(lists[0].list.0.len(), 2);
+        assert_eq!(lists[0].label, "Einkauf 1");
     }
 
     #[test]
@@ -861,23 +1651,753 @@ Montag(4): [[Dish1]](2)
         let dish2 = Dish::from_file(file2.path(), "Salad", 2).unwrap();
 
         let day1 = Day {
+            date: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             dishes: vec![dish1, dish2],
             shopping_days: vec![],
+            ..Default::default()
         };
 
         let weekplan = WeekPlan {
-            _start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            start: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
             days: vec![day1],
+            diagnostics: Report::default(),
         };
 
         let markdown = weekplan.dishes_as_markdown();
 
-        assert!(markdown.contains("# Tag 1"));
+        assert!(markdown.contains("# Donnerstag, 01.01.2026"));
         assert!(markdown.contains("## Pasta (4 Personen)"));
         assert!(markdown.contains("## Salad (2 Personen)"));
         assert!(markdown.contains("- 200.0 g Butter")); // Pasta scaled to 4
-        assert!(markdown.contains("- 100.0 g Butter")); // Salad at 2
+        assert!(markdown.contains("- 100 g Butter")); // Salad at 2
         assert!(markdown.contains("## Zubereitung"));
         assert!(markdown.contains("1. Mix everything together."));
     }
+
+    #[test]
+    fn test_weekplan_from_file_reports_missing_and_broken_dishes() {
+        let menu_content = r#"Personen: 2
+Starttag: 2026-01-01
+Donnerstag: [[Dish1]], [[Unknown]]
+Freitag: [[Broken]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+- 5/0 kg Kartoffeln
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+        std::fs::write(temp_dir.path().join("Broken.txt"), "not a dish\n").unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 3);
+
+        assert_eq!(diagnostics[0].at.line, 3);
+        assert_eq!(diagnostics[0].at.column, 13);
+        assert!(matches!(
+            &diagnostics[0].issue,
+            Issue::UnparseableQuantity { ingredient, .. } if ingredient == "Kartoffeln"
+        ));
+
+        assert_eq!(diagnostics[1].at.line, 3);
+        assert_eq!(
+            diagnostics[1].issue,
+            Issue::MissingDish {
+                dish: "Unknown".to_string()
+            }
+        );
+
+        assert_eq!(diagnostics[2].at.line, 4);
+        assert!(matches!(
+            &diagnostics[2].issue,
+            Issue::DishParse { dish, .. } if dish == "Broken"
+        ));
+    }
+
+    #[test]
+    fn test_weekplan_keeps_partly_readable_dishes() {
+        let menu_file =
+            create_test_dish_file("Personen: 4\nStarttag: 2026-01-01\nDonnerstag: [[Kuchen]]\n");
+        let dish_content = "2 Personen\n\n## Zutaten\n- 100 g Butter\n## Teig\n- 3 Eier\n";
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Kuchen.txt"), dish_content).unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        assert_eq!(weekplan.days[0].dishes[0].ingredients.len(), 2);
+        assert_eq!(
+            weekplan.diagnostics.summary(),
+            "1 problem(s) found in plan:\n  \
+             3:13: dish [[Kuchen]] line 5: cannot read '## Teig', leaving it out\n"
+        );
+        let trips = weekplan.shopping_lists();
+        assert_eq!(trips[0].list.0.len(), 2);
+        assert_eq!(
+            trips[0].extras,
+            ["- [ ] ## Teig (Kuchen, Zeile 5 nicht gelesen)"]
+        );
+    }
+
+    #[test]
+    fn test_weekplan_dish_pages_use_dates() {
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let file = create_test_dish_file(dish_content);
+
+        let day1 = Day {
+            name: "Dienstag".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            dishes: vec![Dish::from_file(file.path(), "Pasta", 2).unwrap()],
+            shopping_days: vec![],
+            ..Default::default()
+        };
+        let day2 = Day {
+            name: "Mittwoch".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+            dishes: vec![
+                Dish::from_file(file.path(), "Salad", 2).unwrap(),
+                Dish::from_file(file.path(), "Soup", 2).unwrap(),
+            ],
+            shopping_days: vec![],
+            ..Default::default()
+        };
+
+        let weekplan = WeekPlan {
+            start: chrono::NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            days: vec![day1, day2],
+            diagnostics: Report::default(),
+        };
+
+        let pages = weekplan.dish_pages();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0].header, "Dienstag, 31.03.2026");
+        assert_eq!(pages[1].header, "Mittwoch, 01.04.2026");
+        assert!(pages[2].markdown.contains("## Soup (2 Personen)"));
+    }
+
+    #[test]
+    fn test_weekplan_from_file_dates_and_weekday_mismatch() {
+        let menu_content = r#"Personen: 2
+Starttag: 2026-04-02
+Donnerstag: [[Dish1]]
+Samstag: [[Dish1]]
+Freitag: [[Dish1]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        assert_eq!(weekplan.days.len(), 3);
+        assert_eq!(weekplan.days[1].name, "Samstag");
+        assert_eq!(weekplan.days[0].label(), "Donnerstag, 02.04.2026");
+        assert_eq!(weekplan.days[2].label(), "Samstag, 04.04.2026");
+
+        let arrival = Day {
+            name: "Anreise".to_string(),
+            date: chrono::NaiveDate::from_ymd_opt(2026, 4, 1).unwrap(),
+            ..Default::default()
+        };
+        assert_eq!(arrival.label(), "Anreise, 01.04.2026");
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].at.line, 4);
+        assert_eq!(
+            diagnostics[0].issue.to_string(),
+            "day 'Samstag' falls on Freitag, 03.04.2026"
+        );
+        assert_eq!(diagnostics[1].at.line, 5);
+    }
+
+    #[test]
+    fn test_serve_groups_keeps_vegan_share_within_head_count() {
+        let file = create_test_dish_file("2 Personen\n\n## Zutaten\n- 100 g Butter\n");
+        let mut dishes = vec![
+            Dish::from_file(file.path(), "Gulasch", 10).unwrap(),
+            Dish::from_file(file.path(), "Kuchen", 10).unwrap(),
+            Dish::from_file(file.path(), "Suppe", 10).unwrap(),
+        ];
+        for (dish, vegan) in dishes.iter_mut().zip([8, 10, 3]) {
+            dish.vegan = Some(VeganPortions {
+                people: vegan,
+                ..Default::default()
+            });
+        }
+        let temp_dir = TempDir::new().unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let mut ctx = PlanContext {
+            content: "",
+            cookbook: &cookbook,
+            groups: Groups(vec![(Diet::Vegan, 2)]),
+            leftovers: Leftovers::default(),
+            diagnostics: Report::default(),
+        };
+
+        let at = Position { line: 1, column: 1 };
+        serve_groups(&mut ctx, &mut dishes, &[false; 3], 6, "Donnerstag", at);
+
+        let shares: Vec<(Option<usize>, usize)> = dishes
+            .iter()
+            .map(|dish| (dish.people, dish.vegan.as_ref().unwrap().people))
+            .collect();
+        assert_eq!(shares, [(Some(6), 6), (Some(6), 6), (Some(6), 3)]);
+    }
+
+    #[test]
+    fn test_weekplan_from_file_vegan_portions() {
+        let menu_content = r#"Personen: 10
+Starttag: 2026-01-01
+Donnerstag: [[Dish1|vegan 4]], [[Dish1|vegan]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+- 200 g Mehl
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        assert!(weekplan.diagnostics.is_empty());
+        let dishes = &weekplan.days[0].dishes;
+        assert_eq!(dishes.len(), 2);
+        assert_eq!(dishes[0].name, "Dish1");
+        assert_eq!(dishes[0].vegan.as_ref().unwrap().people, 4);
+        assert_eq!(dishes[1].vegan.as_ref().unwrap().people, 10);
+
+        let items = dishes[0].shopping_list();
+        let butter = items.iter().find(|i| i.name == "Butter").unwrap();
+        assert!((butter.amount - 300.0).abs() < 1e-4);
+        let vegan_butter = items.iter().find(|i| i.name == "Vegane Butter").unwrap();
+        assert!((vegan_butter.amount - 200.0).abs() < 1e-4);
+
+        let items = dishes[1].shopping_list();
+        assert!(items.iter().all(|i| i.name != "Butter"));
+    }
+
+    #[test]
+    fn test_weekplan_from_file_batches() {
+        let menu_content = r#"Personen: 10
+Starttag: 2026-01-01
+Donnerstag: [[Muffins|Stück]](42), [[Muffins]], [[Dish1|Stück]](4)
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let muffins = r#"12 Stück
+Pro Person: 2
+
+## Zutaten
+- 200 g Mehl
+- 3 Eier
+"#;
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Muffins.md"), muffins).unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        let dishes = &weekplan.days[0].dishes;
+        // 42 muffins need 4 batches, 10 persons eat 20 muffins in 2 batches.
+        assert_eq!(dishes[0].pieces, Some(42));
+        assert_eq!(dishes[0].people, Some(21));
+        assert_eq!(dishes[0].batches(), Some(4));
+        assert_eq!(dishes[0].shopping_list()[0].amount, 800.0);
+        assert_eq!(dishes[1].batches(), Some(2));
+        assert_eq!(dishes[1].shopping_list()[1].amount, 6.0);
+        assert_eq!(dishes[2].people, Some(4));
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].issue.to_string(),
+            "dish [[Dish1]] has unknown option 'Stück', ignoring it"
+        );
+    }
+
+    #[test]
+    fn test_weekplan_missing_file_is_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path());
+
+        let error = WeekPlan::from_file(&temp_dir.path().join("plan.md"), &cookbook)
+            .err()
+            .unwrap();
+
+        assert!(error.to_string().starts_with("Failed to read plan file: "));
+    }
+
+    #[test]
+    fn test_weekplan_from_table() {
+        let menu_content = r#"Personen: 10
+Starttag: 2026-04-02
+
+|                      | Donnerstag           | Freitag (8)           |
+| -------------------- | -------------------- | --------------------- |
+| **Frühstück**        |                      | Standard              |
+| **Mittagessen** (12) | [[Dish1]]            | ⟨Einkauf⟩ [[Dish1]](4), Brot |
+| **Abendessen**       | Maultaschen in Brühe | [[Fehlt]]             |
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+        std::fs::write(temp_dir.path().join("Standard.md"), dish_content).unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        assert_eq!(weekplan.days.len(), 2);
+        let thursday = &weekplan.days[0];
+        assert_eq!(thursday.label(), "Donnerstag, 02.04.2026");
+        assert_eq!(thursday.slots.len(), 3);
+        assert_eq!(thursday.dishes.len(), 1);
+        assert_eq!(thursday.dishes[0].people, Some(12));
+        assert_eq!(thursday.slots[2].notes, ["Maultaschen in Brühe"]);
+
+        let friday = &weekplan.days[1];
+        assert_eq!(friday.dishes.len(), 2);
+        assert_eq!(friday.slots[0].dishes, 0..1);
+        assert_eq!(friday.dishes[0].name, "Standard");
+        assert_eq!(friday.dishes[0].people, Some(8));
+        assert_eq!(friday.dishes[1].people, Some(4));
+        assert_eq!(friday.slots[1].notes, ["Brot"]);
+        assert_eq!(friday.slots[2].people, Some(8));
+        assert_eq!(friday.shopping_days[0].index, 1);
+        assert!(friday.shopping_days[0].label.is_empty());
+        assert_eq!(weekplan.shopping_lists().len(), 2);
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].at.line, 8);
+        assert_eq!(
+            diagnostics[0].issue.to_string(),
+            "dish [[Fehlt]] not found in cookbook"
+        );
+
+        let markdown = weekplan.dishes_as_markdown();
+        assert!(markdown.contains(
+            "# Donnerstag, 02.04.2026\n\n## Mittagessen (12 Personen)\n\n### Dish1 (12 Personen)\n\n#### Zutaten\n"
+        ));
+        assert!(markdown.contains("## Abendessen\n\n- Maultaschen in Brühe\n"));
+        assert!(markdown.contains("## Frühstück (8 Personen)\n\n### Standard (8 Personen)"));
+        assert!(!markdown.contains("## Kaffee"));
+    }
+
+    #[test]
+    fn test_weekplan_renders_days_with_only_notes() {
+        let table_file = create_test_dish_file(include_str!("test-data/table.md"));
+        let temp_dir = TempDir::new().unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(table_file.path(), &cookbook).unwrap();
+
+        assert!(weekplan.days[0].dishes.is_empty());
+        let markdown = weekplan.dishes_as_markdown();
+        assert!(markdown.starts_with(
+            "# Donnerstag, 01.01.2026\n\n## Abendessen\n\n- Maultaschen in Brühe\n\n\n# Freitag"
+        ));
+    }
+
+    #[test]
+    fn test_weekplan_attendance() {
+        let attendance = Attendance::parse(
+            "| Kinder  | 30 | Donnerstag Abendessen | Freitag Mittagessen |\n\
+             | Leitung | 8  | Donnerstag            | Freitag             |\n",
+        );
+        let dish_content = "2 Personen\n\n## Zutaten\n- 100 g Butter\n";
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path()).with_attendance(Some(attendance));
+
+        let table_file = create_test_dish_file(
+            "Personen: 43\nStarttag: 2026-04-02\n\n\
+             | | Donnerstag | Freitag |\n\
+             | --- | --- | --- |\n\
+             | **Frühstück** | [[Dish1]] | [[Dish1]](20) |\n\
+             | **Mittagessen** | [[Dish1]] | [[Dish1]] |\n\
+             | **Abendessen** (40) | [[Dish1]] | [[Dish1]] |\n",
+        );
+        let weekplan = WeekPlan::from_file(table_file.path(), &cookbook).unwrap();
+
+        let people = |day: &Day| -> Vec<Option<usize>> {
+            day.dishes.iter().map(|dish| dish.people).collect()
+        };
+        assert_eq!(people(&weekplan.days[0]), [Some(8), Some(8), Some(40)]);
+        assert_eq!(people(&weekplan.days[1]), [Some(20), Some(38), Some(40)]);
+        assert_eq!(weekplan.days[0].people, 38);
+        assert_eq!(
+            weekplan.head_counts(),
+            "| Tag | Frühstück | Mittagessen | Abendessen |\n\
+             | --- | ---: | ---: | ---: |\n\
+             | Donnerstag, 02.04.2026 | 8 | 8 | 40 |\n\
+             | Freitag, 03.04.2026 | 38 | 38 | 40 |\n"
+        );
+        assert_eq!(
+            weekplan.diagnostics.0[0].to_string(),
+            "1:1: plan has 43 participants but the attendance up to 38, using the attendance"
+        );
+
+        let line_file = create_test_dish_file(
+            "Personen: 43\nStarttag: 2026-04-02\nDonnerstag: [[Dish1]]\n\
+             Freitag: [[Dish1]]\nSamstag (5): [[Dish1]]\n",
+        );
+        let weekplan = WeekPlan::from_file(line_file.path(), &cookbook).unwrap();
+        let days: Vec<usize> = weekplan.days.iter().map(|day| day.people).collect();
+        assert_eq!(days, [38, 38, 5]);
+        assert_eq!(weekplan.days[1].dishes[0].people, Some(38));
+        assert_eq!(
+            weekplan.head_counts(),
+            "| Tag | Personen |\n\
+             | --- | ---: |\n\
+             | Donnerstag, 02.04.2026 | 38 |\n\
+             | Freitag, 03.04.2026 | 38 |\n\
+             | Samstag, 04.04.2026 | 5 |\n"
+        );
+        assert_eq!(weekplan.diagnostics.0.len(), 1);
+    }
+
+    #[test]
+    fn test_weekplan_reports_days_without_participants() {
+        let attendance = Attendance::parse(include_str!("test-data/attendance.md"));
+        let dish_content = "2 Personen\n\n## Zutaten\n- 100 g Butter\n";
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path()).with_attendance(Some(attendance));
+
+        // Everyone has left on Sunday, the plan runs until Monday.
+        let line_file = create_test_dish_file(
+            "Personen: 43\nStarttag: 2026-04-02\nDonnerstag: [[Dish1]]\n\
+             Freitag: [[Dish1]]\nSamstag: [[Dish1]]\nSonntag: [[Dish1]]\nMontag: [[Dish1]]\n",
+        );
+        let weekplan = WeekPlan::from_file(line_file.path(), &cookbook).unwrap();
+        assert_eq!(weekplan.days[4].people, 0);
+        assert_eq!(
+            weekplan.diagnostics.summary(),
+            "1 problem(s) found in plan:\n  \
+             7:1: Montag, 06.04.2026 has no participants, scaling its dishes to 0\n"
+        );
+
+        // The Kinder skip the first breakfast, nobody stays after Friday.
+        let attendance = Attendance::parse("| Kinder | 30 | Donnerstag Mittagessen | Freitag |\n");
+        let cookbook = CookBook::from_file(temp_dir.path()).with_attendance(Some(attendance));
+        let table_file = create_test_dish_file(
+            "Starttag: 2026-04-02\n\n\
+             | | Donnerstag | Freitag | Samstag |\n\
+             | --- | --- | --- | --- |\n\
+             | **Frühstück** | [[Dish1]] | [[Dish1]] | [[Dish1]] |\n\
+             | **Mittagessen** | [[Dish1]] | [[Dish1]] | [[Dish1]](4) |\n",
+        );
+        let weekplan = WeekPlan::from_file(table_file.path(), &cookbook).unwrap();
+        let issues: Vec<String> = weekplan
+            .diagnostics
+            .0
+            .iter()
+            .map(|diagnostic| diagnostic.issue.to_string())
+            .filter(|issue| issue.contains("no participants"))
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                "Donnerstag, 02.04.2026, Frühstück has no participants, scaling its dishes to 0",
+                "Samstag, 04.04.2026 has no participants, scaling its dishes to 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_dish_options() {
+        assert_eq!(
+            parse_dish_options("Gulasch"),
+            ("Gulasch", DishOptions::default())
+        );
+        assert_eq!(
+            parse_dish_options("Muffins | Stück | vegan 6"),
+            (
+                "Muffins",
+                DishOptions {
+                    vegan: Some(Some(6)),
+                    unit: Some("Stück".to_string()),
+                    surplus: None,
+                    unknown: vec![],
+                }
+            )
+        );
+        assert_eq!(parse_dish_options("Muffins|Personen").1.unit, None);
+        assert_eq!(parse_dish_options("Gulasch|vegan").1.vegan, Some(None));
+        assert_eq!(parse_dish_options("Gulasch|+20%").1.surplus, Some(0.2));
+        assert_eq!(
+            parse_dish_options("Gulasch|vegetarisch|vgan|vegan x").1,
+            DishOptions {
+                unit: Some("vegetarisch".to_string()),
+                unknown: vec!["vgan".to_string(), "vegan x".to_string()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_weekplan_from_file_nutrition() {
+        let menu_content = r#"Personen: 4
+Starttag: 2026-01-01
+Donnerstag: [[Dish1]], [[Dish1]] (2)
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 100 g Butter
+- 200 g Mehl
+- 1 Prise Salz
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+
+        let table = NutrientTable::parse(
+            "| Butter | 740 | 0,7 | 82 | 54 | 0,6 | 0,6 | 0 | 0 | |\n| Mehl | 350 | 10 | 1 | 0,2 | 72 | 0,7 | 4 | 0 | |\n",
+        );
+        let cookbook = CookBook::from_file(temp_dir.path()).with_nutrients(Some(table));
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        // 740 kcal Butter + 700 kcal Mehl for 2 persons, twice a day.
+        let day = &weekplan.days[0];
+        let nutrition = day.dishes[0].nutrition.as_ref().unwrap();
+        assert!((nutrition.per_person().kcal - 720.0).abs() < 1e-2);
+        assert!((day.kcal_per_person().unwrap() - 1440.0).abs() < 1e-2);
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(
+            diagnostics[0].issue.to_string(),
+            "dish [[Dish1]] has no nutrient data for Salz"
+        );
+
+        let markdown = weekplan.dishes_as_markdown();
+        assert!(markdown.contains("Energie pro Person: 1440 kcal"));
+        assert!(markdown.contains("**Nährwerte pro Person:** 720 kcal"));
+        assert!(markdown.contains("(ohne Salz)"));
+    }
+
+    #[test]
+    fn test_weekplan_participant_groups() {
+        let menu_content = r#"Personen: 43 (vegan 12, vegetarisch 8, halal 3)
+Starttag: 2026-01-01
+Donnerstag: [[Gulasch]], [[Gulasch (vegan)]], [[Brot]](10)
+Freitag (40): [[Gulasch]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let temp_dir = TempDir::new().unwrap();
+        let dish = "4 Personen\n\n## Zutaten\n- 400 g Rind\n";
+        std::fs::write(temp_dir.path().join("Gulasch.txt"), dish).unwrap();
+        std::fs::write(
+            temp_dir.path().join("Gulasch (vegan).txt"),
+            "4 Personen\nErnährung: vegan\n\n## Zutaten\n- 400 g Seitan\n",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("Brot.txt"), dish).unwrap();
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        let people: Vec<Option<usize>> = weekplan.days[0]
+            .dishes
+            .iter()
+            .map(|dish| dish.people)
+            .collect();
+        assert_eq!(people, [Some(23), Some(20), Some(10)]);
+        assert_eq!(weekplan.days[0].dishes[1].diets, [Diet::Vegan]);
+        assert_eq!(weekplan.days[1].dishes[0].people, Some(40));
+
+        let messages: Vec<String> = weekplan
+            .diagnostics
+            .0
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "1:40: unknown participant group 'halal 3', ignoring it",
+                "4:1: Freitag, 02.01.2026 has no dish known to be vegan for 12 participants",
+                "4:1: Freitag, 02.01.2026 has no dish known to be vegetarisch for 8 participants",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_weekplan_allergens() {
+        let menu_content = r#"Personen: 4
+Starttag: 2026-01-01
+Donnerstag: [[Kuchen]], [[Salat]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("Kuchen.txt"),
+            "2 Personen\n\n## Zutaten\n- 100 g Butter\n- 200 g Mehl\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("Salat.txt"),
+            "2 Personen\n\n## Zutaten\n- 1 Kopf Salat\n- 1 EL Senf\n- 1 Prise Salz\n",
+        )
+        .unwrap();
+
+        let table =
+            AllergenTable::parse("Butter: Milch\nMehl: Gluten\nKopf Salat: -\nSenf: Senf\n")
+                .unwrap();
+        let cookbook = CookBook::from_file(temp_dir.path()).with_allergens(Some(table));
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].issue.to_string(),
+            "dish [[Salat]] has no allergen data for Salz"
+        );
+
+        let markdown = weekplan.dishes_as_markdown();
+        assert!(markdown.contains("**Allergene:** Gluten, Milch (vegetarisch)\n"));
+        assert!(markdown.contains("**Allergene:** Senf, ohne Angaben für Salz\n"));
+
+        let matrix = weekplan.allergen_matrix();
+        assert!(matrix.starts_with("# Donnerstag, 01.01.2026\n\n| Gericht | Gluten |"));
+        assert!(matrix.contains("| Kuchen | x |  |  |  |  |  | x |"));
+        assert!(matrix.contains("| Salat | ? |"));
+    }
+
+    #[test]
+    fn test_weekplan_from_file_costs() {
+        let menu_content = r#"Personen: 4
+Starttag: 2026-01-01
+Donnerstag: [[Dish1]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let dish_content = r#"2 Personen
+
+## Zutaten
+- 125 g Butter
+- 1 Prise Salz
+
+## Zubereitung
+1. Mix everything together.
+"#;
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("Dish1.txt"), dish_content).unwrap();
+
+        let prices = Prices::parse("| Butter | 2,00 | 250 g |\n");
+        let cookbook = CookBook::from_file(temp_dir.path()).with_prices(Some(prices));
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        let costs = weekplan.days[0].dishes[0].costs.as_ref().unwrap();
+        assert!((costs.total() - 2.0).abs() < 1e-4);
+        assert_eq!(costs.missing(), vec!["Salz"]);
+
+        let markdown = weekplan.dishes_as_markdown();
+        assert!(markdown.contains("**Kosten pro Person:** 0,50 € (ohne Salz)"));
+    }
+
+    #[test]
+    fn test_weekplan_from_file_expands_sub_dishes() {
+        let menu_content = r#"Personen: 4
+Starttag: 2026-01-01
+Donnerstag: [[Semmelknödel]], [[Kreis]]
+"#;
+        let menu_file = create_test_dish_file(menu_content);
+
+        let temp_dir = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            std::fs::write(temp_dir.path().join(format!("{name}.md")), content).unwrap();
+        };
+        write(
+            "Semmelknödel",
+            "2 Personen\n\n## Zutaten\n- 6 Brötchen\n- 500 ml [[Bratensoße]]\n",
+        );
+        write(
+            "Bratensoße",
+            "4 Personen\nErgibt: 1 l\n\n## Zutaten\n- 800 ml Brühe\n- 100 g [[Röstzwiebeln]]\n",
+        );
+        // No declared yield, 200 g of ingredients make one recipe.
+        write(
+            "Röstzwiebeln",
+            "2 Personen\n\n## Zutaten\n- 150 g Zwiebeln\n- 50 g Mehl\n",
+        );
+        write("Kreis", "2 Personen\n\n## Zutaten\n- 1 [[Kreis]]\n");
+
+        let cookbook = CookBook::from_file(temp_dir.path());
+        let weekplan = WeekPlan::from_file(menu_file.path(), &cookbook).unwrap();
+
+        let diagnostics = &weekplan.diagnostics.0;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].issue.to_string(),
+            "dish [[Kreis]] references itself: Kreis → Kreis"
+        );
+
+        let dish = &weekplan.days[0].dishes[0];
+        let items = dish.shopping_list();
+        assert_eq!(items.len(), 4);
+        let broth = items.iter().find(|i| i.name == "Brühe").unwrap();
+        assert!((broth.amount - 800.0).abs() < 1e-3);
+        assert_eq!(broth.dish, "Bratensoße ← Semmelknödel");
+        let onions = items.iter().find(|i| i.name == "Zwiebeln").unwrap();
+        assert!((onions.amount - 75.0).abs() < 1e-3);
+        assert_eq!(onions.dish, "Röstzwiebeln ← Bratensoße ← Semmelknödel");
+
+        let markdown = dish.as_markdown();
+        assert!(markdown.contains("- 1000.0 ml [[Bratensoße]]\n  - 800.0 ml Brühe\n"));
+    }
 }
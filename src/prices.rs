@@ -0,0 +1,254 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::{aliases::Aliases, types::Ingredient, units::convert};
+
+/// Price of a single package.
+#[derive(Debug, Clone, PartialEq)]
+struct Package {
+    /// Price in Euro.
+    price: f32,
+    /// Package size.
+    amount: f32,
+    /// Unit of the package size, empty for pieces.
+    measure: String,
+}
+
+impl Package {
+    /// Convert an amount into package sizes, if the units are compatible.
+    fn packages(&self, amount: f32, measure: &str) -> Option<f32> {
+        if self.amount <= 0.0 {
+            return None;
+        }
+        convert(amount, measure, &self.measure).map(|amount| amount / self.amount)
+    }
+}
+
+/// Local price catalog keyed by canonical ingredient name.
+///
+/// Read from a markdown table with the price per package and its size:
+///
+/// ```text
+/// | Zutat  | Preis | Packung |
+/// | Butter | 2,19  | 250 g   |
+/// | Eier   | 2,99  | 10      |
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct Prices {
+    packages: HashMap<String, Package>,
+}
+
+impl Prices {
+    /// Load the catalog, returns `None` if the file does not exist.
+    pub(crate) fn from_file(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read price file: {}", path.display()))?;
+        Ok(Some(Self::parse(&content)))
+    }
+
+    /// Parse table rows, the header and malformed rows are skipped.
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut packages = HashMap::new();
+
+        for line in content.lines().map(str::trim) {
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+            let [name, price, package] = cells[..] else {
+                continue;
+            };
+            let Ok(price) = parse_number(price.trim_end_matches('€').trim()) else {
+                continue;
+            };
+            let (amount, measure) = match package.split_once(' ') {
+                Some((amount, measure)) => match parse_number(amount) {
+                    Ok(amount) => (amount, measure.trim()),
+                    Err(_) => (1.0, package),
+                },
+                None => match parse_number(package) {
+                    Ok(amount) => (amount, ""),
+                    Err(_) => (1.0, package),
+                },
+            };
+
+            packages.insert(
+                name.to_lowercase(),
+                Package {
+                    price,
+                    amount,
+                    measure: measure.to_string(),
+                },
+            );
+        }
+
+        Self { packages }
+    }
+
+    /// Cost of a single ingredient, `None` without a matching price entry.
+    ///
+    /// Ingredients rounded up to whole packages are priced by what is bought.
+    pub(crate) fn cost(&self, ingredient: &Ingredient, aliases: &Aliases) -> Option<f32> {
+        let name = aliases.canonical(&ingredient.name).to_lowercase();
+        let package = self.packages.get(&name)?;
+        let (amount, measure) = match &ingredient.packed {
+            Some(packed) => (packed.count as f32 * packed.size, packed.measure.as_str()),
+            None => (ingredient.amount, ingredient.measure.as_str()),
+        };
+        package
+            .packages(amount, measure)
+            .map(|packages| packages * package.price)
+    }
+
+    /// Cost of every ingredient of a list.
+    pub(crate) fn costs(&self, ingredients: &[Ingredient], aliases: &Aliases) -> Costs {
+        Costs(
+            ingredients
+                .iter()
+                .map(|ingredient| (ingredient.name.clone(), self.cost(ingredient, aliases)))
+                .collect(),
+        )
+    }
+}
+
+fn parse_number(text: &str) -> Result<f32, std::num::ParseFloatError> {
+    text.replace(',', ".").parse()
+}
+
+/// Cost per ingredient, `None` for ingredients without a price.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Costs(pub(crate) Vec<(String, Option<f32>)>);
+
+impl Costs {
+    /// Sum over all ingredients with a price.
+    pub(crate) fn total(&self) -> f32 {
+        self.0.iter().filter_map(|(_, cost)| *cost).sum()
+    }
+
+    /// Ingredients without a price entry.
+    pub(crate) fn missing(&self) -> Vec<&str> {
+        self.0
+            .iter()
+            .filter(|(_, cost)| cost.is_none())
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Cost of every priced ingredient followed by the subtotal and the ones without a price.
+    pub(crate) fn as_markdown(&self, title: &str) -> String {
+        let mut output = String::from("### Kosten\n");
+        for (name, cost) in &self.0 {
+            if let Some(cost) = cost {
+                output.push_str(&format!("- {name}: {}\n", euro(*cost)));
+            }
+        }
+        output.push_str(&format!("\n**{title}: {}**", euro(self.total())));
+        let missing = self.missing();
+        if !missing.is_empty() {
+            output.push_str(&format!("\n\nOhne Preis: {}", missing.join(", ")));
+        }
+        output
+    }
+}
+
+/// Format an amount in Euro with a decimal comma, e.g. "4,38 €".
+pub(crate) fn euro(amount: f32) -> String {
+    format!("{amount:.2} €").replace('.', ",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packages::Packed;
+
+    fn make_ingredient(amount: f32, measure: &str, name: &str) -> Ingredient {
+        Ingredient {
+            amount,
+            measure: measure.to_string(),
+            name: name.to_string(),
+            dish: "Dish".to_string(),
+            packed: None,
+            quantity: None,
+        }
+    }
+
+    const CATALOG: &str = r#"
+| Zutat     | Preis  | Packung |
+| --------- | ------ | ------- |
+| Butter    | 2,20 € | 250 g   |
+| Ei        | 3,00   | 10      |
+| Milch     | 1,10   | 1 l     |
+| Mais      | 0,90   | Dose    |
+| kaputt    | teuer  | 1 kg    |
+"#;
+
+    #[test]
+    fn test_parse_catalog() {
+        let prices = Prices::parse(CATALOG);
+
+        assert_eq!(prices.packages.len(), 4);
+        assert_eq!(
+            prices.packages["butter"],
+            Package {
+                price: 2.2,
+                amount: 250.0,
+                measure: "g".to_string(),
+            }
+        );
+        assert_eq!(prices.packages["ei"].measure, "");
+        assert_eq!(prices.packages["mais"].measure, "Dose");
+    }
+
+    #[test]
+    fn test_cost_converts_units() {
+        let prices = Prices::parse(CATALOG);
+        let aliases = Aliases::parse("Ei: Eier\n");
+
+        let cost =
+            |amount, measure, name| prices.cost(&make_ingredient(amount, measure, name), &aliases);
+
+        assert!((cost(1.0, "kg", "Butter").unwrap() - 8.8).abs() < 1e-4);
+        assert!((cost(5.0, "", "Eier").unwrap() - 1.5).abs() < 1e-4);
+        assert!((cost(5.0, "Stück", "Ei").unwrap() - 1.5).abs() < 1e-4);
+        assert!((cost(500.0, "ml", "Milch").unwrap() - 0.55).abs() < 1e-4);
+        assert!((cost(2.0, "Dose", "Mais").unwrap() - 1.8).abs() < 1e-4);
+        assert_eq!(cost(1.0, "EL", "Butter"), None);
+        assert_eq!(cost(1.0, "Prise", "Salz"), None);
+    }
+
+    #[test]
+    fn test_cost_of_packed_purchase() {
+        let prices = Prices::parse(CATALOG);
+        let mut butter = make_ingredient(300.0, "g", "Butter");
+        butter.packed = Some(Packed {
+            count: 2,
+            size: 250.0,
+            measure: "g".to_string(),
+        });
+
+        let cost = prices.cost(&butter, &Aliases::default()).unwrap();
+        assert!((cost - 4.4).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_costs_total_and_missing() {
+        let prices = Prices::parse(CATALOG);
+        let costs = prices.costs(
+            &[
+                make_ingredient(500.0, "g", "Butter"),
+                make_ingredient(1.0, "Prise", "Salz"),
+            ],
+            &Aliases::default(),
+        );
+
+        assert!((costs.total() - 4.4).abs() < 1e-4);
+        assert_eq!(costs.missing(), vec!["Salz"]);
+
+        let markdown = costs.as_markdown("Summe Einkauf 1");
+        assert!(markdown.contains("- Butter: 4,40 €"));
+        assert!(!markdown.contains("- Salz"));
+        assert!(markdown.contains("**Summe Einkauf 1: 4,40 €**"));
+        assert!(markdown.ends_with("Ohne Preis: Salz"));
+    }
+}
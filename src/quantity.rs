@@ -0,0 +1,223 @@
+use std::sync::LazyLock;
+
+use clap::ValueEnum;
+use regex::Regex;
+
+/// Unicode vulgar fractions and their values.
+const FRACTIONS: &[(char, f32)] = &[
+    ('½', 1.0 / 2.0),
+    ('⅓', 1.0 / 3.0),
+    ('⅔', 2.0 / 3.0),
+    ('¼', 1.0 / 4.0),
+    ('¾', 3.0 / 4.0),
+    ('⅕', 1.0 / 5.0),
+    ('⅖', 2.0 / 5.0),
+    ('⅗', 3.0 / 5.0),
+    ('⅘', 4.0 / 5.0),
+    ('⅙', 1.0 / 6.0),
+    ('⅚', 5.0 / 6.0),
+    ('⅛', 1.0 / 8.0),
+    ('⅜', 3.0 / 8.0),
+    ('⅝', 5.0 / 8.0),
+    ('⅞', 7.0 / 8.0),
+];
+
+/// A single number: mixed number, fraction, decimal or Unicode fraction.
+const NUMBER: &str =
+    r"(?:\d+\s*[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]|\d+\s+\d+/\d+|\d+/\d+|\d+(?:[.,]\d+)?|[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞])";
+
+/// A number or a range of two numbers at the start of a text.
+static QUANTITY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"^({NUMBER})(?:\s*[-–]\s*({NUMBER}))?(?:\s|$)")).unwrap()
+});
+
+/// Which amount of a range like "2-3 EL" is used for scaling and shopping.
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub(crate) enum RangePolicy {
+    /// The smaller amount.
+    Lower,
+    /// The larger amount.
+    Upper,
+    /// The average of both.
+    #[default]
+    Mean,
+}
+
+/// A quantity as written in a recipe, e.g. "2,5", "1 ½" or "2-3".
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Quantity {
+    /// Original text.
+    pub(crate) text: String,
+    pub(crate) lower: f32,
+    pub(crate) upper: f32,
+}
+
+impl Quantity {
+    /// Parse a complete quantity text.
+    pub(crate) fn parse(text: &str) -> Option<Self> {
+        let (quantity, rest) = Self::parse_prefix(text)?;
+        rest.trim().is_empty().then_some(quantity)
+    }
+
+    /// Parse the quantity at the start of a text and return the remainder.
+    ///
+    /// Fails for number-like prefixes that are not a valid quantity, such as
+    /// a zero denominator or a falling range.
+    pub(crate) fn parse_prefix(text: &str) -> Option<(Self, &str)> {
+        let text = text.trim_start();
+        let captures = QUANTITY.captures(text)?;
+        let lower = number(&captures[1])?;
+        let upper = match captures.get(2) {
+            Some(upper) => number(upper.as_str())?,
+            None => lower,
+        };
+        if upper < lower {
+            return None;
+        }
+        let matched = captures[0].trim_end();
+
+        Some((
+            Self {
+                text: matched.to_string(),
+                lower,
+                upper,
+            },
+            &text[matched.len()..],
+        ))
+    }
+
+    /// The amount to calculate with.
+    pub(crate) fn value(&self, policy: RangePolicy) -> f32 {
+        match policy {
+            RangePolicy::Lower => self.lower,
+            RangePolicy::Upper => self.upper,
+            RangePolicy::Mean => (self.lower + self.upper) / 2.0,
+        }
+    }
+
+    pub(crate) fn is_range(&self) -> bool {
+        self.lower != self.upper
+    }
+
+    /// The quantity multiplied by a factor, the original text is kept for a factor of one.
+    ///
+    /// Scaled amounts use one decimal with the separator of the original text.
+    pub(crate) fn scaled(&self, factor: f32) -> Self {
+        if (factor - 1.0).abs() < 1e-6 {
+            return self.clone();
+        }
+        let (lower, upper) = (self.lower * factor, self.upper * factor);
+        let text = if self.is_range() {
+            format!("{lower:.1}–{upper:.1}")
+        } else {
+            format!("{lower:.1}")
+        };
+        let text = if self.text.contains(',') {
+            text.replace('.', ",")
+        } else {
+            text
+        };
+        Self { text, lower, upper }
+    }
+
+    /// Text for rendering, as written in the recipe or as scaled.
+    pub(crate) fn render(&self) -> String {
+        self.text.clone()
+    }
+}
+
+/// Value of a single number matched by [`NUMBER`].
+fn number(text: &str) -> Option<f32> {
+    let text = text.trim();
+    if let Some(fraction) = text.chars().last().and_then(unicode_fraction) {
+        let whole = text[..text.len() - text.chars().last()?.len_utf8()].trim();
+        let whole: f32 = if whole.is_empty() {
+            0.0
+        } else {
+            whole.parse().ok()?
+        };
+        return Some(whole + fraction);
+    }
+    if let Some((whole, fraction)) = text.split_once(char::is_whitespace) {
+        return Some(whole.parse::<f32>().ok()? + number(fraction)?);
+    }
+    if let Some((numerator, denominator)) = text.split_once('/') {
+        let denominator: f32 = denominator.parse().ok()?;
+        if denominator == 0.0 {
+            return None;
+        }
+        return Some(numerator.parse::<f32>().ok()? / denominator);
+    }
+    text.replace(',', ".").parse().ok()
+}
+
+fn unicode_fraction(c: char) -> Option<f32> {
+    FRACTIONS
+        .iter()
+        .find(|(fraction, _)| *fraction == c)
+        .map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(text: &str) -> Option<f32> {
+        Quantity::parse(text).map(|q| q.value(RangePolicy::Mean))
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        assert_eq!(value("2"), Some(2.0));
+        assert_eq!(value("2,5"), Some(2.5));
+        assert_eq!(value("0.5"), Some(0.5));
+        assert_eq!(value("½"), Some(0.5));
+        assert_eq!(value("1/4"), Some(0.25));
+        assert_eq!(value("1 ½"), Some(1.5));
+        assert_eq!(value("1½"), Some(1.5));
+        assert_eq!(value("1 3/4"), Some(1.75));
+        assert_eq!(value("1/0"), None);
+        assert_eq!(value("viel"), None);
+    }
+
+    #[test]
+    fn test_parse_ranges_with_policy() {
+        let range = Quantity::parse("2-3").unwrap();
+        assert!(range.is_range());
+        assert_eq!(range.value(RangePolicy::Lower), 2.0);
+        assert_eq!(range.value(RangePolicy::Upper), 3.0);
+        assert_eq!(range.value(RangePolicy::Mean), 2.5);
+
+        assert_eq!(value("½ – 1"), Some(0.75));
+        assert_eq!(value("3-2"), None);
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let (quantity, rest) = Quantity::parse_prefix("2-3 EL Öl").unwrap();
+        assert_eq!(quantity.text, "2-3");
+        assert_eq!(rest, " EL Öl");
+
+        let (quantity, rest) = Quantity::parse_prefix(" 1 ½ TL Salz").unwrap();
+        assert_eq!(quantity.text, "1 ½");
+        assert_eq!(rest, " TL Salz");
+
+        assert!(Quantity::parse_prefix("Salz").is_none());
+        assert!(Quantity::parse_prefix("1/0 l Milch").is_none());
+    }
+
+    #[test]
+    fn test_scaled_and_render() {
+        let quantity = Quantity::parse("½").unwrap();
+        assert_eq!(quantity.scaled(1.0).render(), "½");
+        assert_eq!(quantity.scaled(3.0).render(), "1.5");
+
+        let range = Quantity::parse("2-3").unwrap();
+        assert_eq!(range.scaled(2.0).render(), "4.0–6.0");
+
+        assert_eq!(Quantity::parse("100").unwrap().render(), "100");
+        assert_eq!(Quantity::parse("2,5").unwrap().render(), "2,5");
+        assert_eq!(Quantity::parse("2,5").unwrap().scaled(2.0).render(), "5,0");
+        assert_eq!(Quantity::parse("0.5").unwrap().scaled(3.0).render(), "1.5");
+    }
+}
@@ -0,0 +1,234 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    aliases::Aliases,
+    diagnostics::{Diagnostic, Issue, Position},
+};
+
+/// Exponent used for spices when no rule table overrides it.
+const SPICE_EXPONENT: f32 = 0.7;
+
+/// Ingredients that do not scale linearly, unless a rule file overrides them.
+const BUILTIN: &[(&str, Scaling)] = &[
+    ("Salz", Scaling::Sublinear(SPICE_EXPONENT)),
+    ("Pfeffer", Scaling::Sublinear(SPICE_EXPONENT)),
+    ("Muskat", Scaling::Sublinear(SPICE_EXPONENT)),
+    ("Chili", Scaling::Sublinear(SPICE_EXPONENT)),
+    ("Cayennepfeffer", Scaling::Sublinear(SPICE_EXPONENT)),
+    ("Lorbeerblatt", Scaling::Fixed),
+    ("Ei", Scaling::Whole),
+];
+
+/// How an ingredient amount follows the number of people.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) enum Scaling {
+    /// Proportional to the people.
+    #[default]
+    Linear,
+    /// Grows with the scale factor raised to the exponent, e.g. for spices.
+    Sublinear(f32),
+    /// Same amount regardless of the people, e.g. one Lorbeerblatt per pot.
+    Fixed,
+    /// Proportional, but rounded to whole pieces, e.g. eggs.
+    Whole,
+}
+
+impl Scaling {
+    /// Parse a rule like `linear`, `sublinear 0,7`, `fix` or `ganz`.
+    pub(crate) fn parse(rule: &str) -> Option<Self> {
+        let rule = rule.trim().to_lowercase();
+        match rule.split_whitespace().collect::<Vec<_>>()[..] {
+            ["linear"] => Some(Scaling::Linear),
+            ["sublinear"] => Some(Scaling::Sublinear(SPICE_EXPONENT)),
+            ["sublinear", exponent] => exponent
+                .replace(',', ".")
+                .parse()
+                .ok()
+                .filter(|exponent| (0.0..=1.0).contains(exponent))
+                .map(Scaling::Sublinear),
+            ["fix"] => Some(Scaling::Fixed),
+            ["ganz"] => Some(Scaling::Whole),
+            _ => None,
+        }
+    }
+
+    /// Scale a recipe amount by `factor`.
+    pub(crate) fn apply(self, amount: f32, factor: f32) -> f32 {
+        match self {
+            Scaling::Linear => amount * factor,
+            Scaling::Sublinear(exponent) => amount * factor.powf(exponent),
+            Scaling::Fixed => amount,
+            Scaling::Whole if amount > 0.0 => (amount * factor).round().max(1.0),
+            Scaling::Whole => 0.0,
+        }
+    }
+}
+
+/// Split a dish-file annotation off an ingredient name, e.g. `Salz {sublinear 0,7}`.
+///
+/// Returns the name and the annotation text without braces.
+pub(crate) fn split_annotation(name: &str) -> (&str, Option<&str>) {
+    match name
+        .trim_end()
+        .strip_suffix('}')
+        .and_then(|rest| rest.rsplit_once('{'))
+    {
+        Some((name, rule)) => (name.trim_end(), Some(rule.trim())),
+        None => (name, None),
+    }
+}
+
+/// Global scaling rules keyed by canonical ingredient name.
+///
+/// Read from a file with one `Zutat: Regel` pair per line, entries override
+/// the built-in table. Empty lines and lines starting with `#` are ignored.
+#[derive(Debug)]
+pub(crate) struct ScalingRules(HashMap<String, Scaling>);
+
+impl Default for ScalingRules {
+    fn default() -> Self {
+        Self(
+            BUILTIN
+                .iter()
+                .map(|(name, scaling)| (name.to_lowercase(), *scaling))
+                .collect(),
+        )
+    }
+}
+
+impl ScalingRules {
+    /// Load the rules, a missing file yields the built-in table.
+    ///
+    /// Also returns the lines that are not a known rule, see [`Self::parse`].
+    pub(crate) fn from_file(path: &Path) -> Result<(Self, Vec<Diagnostic>)> {
+        if !path.exists() {
+            return Ok((Self::default(), Vec::new()));
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read scaling file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse the rules together with the lines that were left out.
+    ///
+    /// Lines without a `:` or with an unknown rule are rejected with their
+    /// position, the ingredient keeps its built-in or linear scaling.
+    pub(crate) fn parse(content: &str) -> (Self, Vec<Diagnostic>) {
+        let mut rules = Self::default();
+        let mut rejected = Vec::new();
+
+        for (row, line) in content.lines().enumerate() {
+            let line = line.trim().trim_start_matches("- ");
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parsed = line
+                .split_once(':')
+                .and_then(|(name, rule)| Some((name.trim(), Scaling::parse(rule)?)));
+            match parsed {
+                Some((name, scaling)) => {
+                    rules.0.insert(name.to_lowercase(), scaling);
+                }
+                None => rejected.push(Diagnostic {
+                    at: Position {
+                        line: row + 1,
+                        column: 1,
+                    },
+                    issue: Issue::UnknownScalingRule {
+                        line: line.to_string(),
+                    },
+                }),
+            }
+        }
+
+        (rules, rejected)
+    }
+
+    /// The rule of an ingredient, if it has one.
+    pub(crate) fn get(&self, name: &str, aliases: &Aliases) -> Option<Scaling> {
+        self.0
+            .get(&name.to_lowercase())
+            .or_else(|| self.0.get(&aliases.canonical(name).to_lowercase()))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rules() {
+        assert_eq!(Scaling::parse("linear"), Some(Scaling::Linear));
+        assert_eq!(
+            Scaling::parse("Sublinear 0,5"),
+            Some(Scaling::Sublinear(0.5))
+        );
+        assert_eq!(
+            Scaling::parse("sublinear"),
+            Some(Scaling::Sublinear(SPICE_EXPONENT))
+        );
+        assert_eq!(Scaling::parse("fix"), Some(Scaling::Fixed));
+        assert_eq!(Scaling::parse("ganz"), Some(Scaling::Whole));
+        assert_eq!(Scaling::parse("sublinear 2"), None);
+        assert_eq!(Scaling::parse("quadratisch"), None);
+    }
+
+    #[test]
+    fn test_apply() {
+        assert_eq!(Scaling::Linear.apply(1.0, 21.5), 21.5);
+        assert!((Scaling::Sublinear(0.5).apply(1.0, 16.0) - 4.0).abs() < 1e-4);
+        assert_eq!(Scaling::Fixed.apply(1.0, 21.5), 1.0);
+        assert_eq!(Scaling::Whole.apply(3.0, 2.15), 6.0);
+        assert_eq!(Scaling::Whole.apply(1.0, 0.2), 1.0);
+    }
+
+    #[test]
+    fn test_split_annotation() {
+        assert_eq!(split_annotation("Salz {fix}"), ("Salz", Some("fix")));
+        assert_eq!(
+            split_annotation("Chili {sublinear 0,5} "),
+            ("Chili", Some("sublinear 0,5"))
+        );
+        assert_eq!(
+            split_annotation("Äpfel (säuerlich)"),
+            ("Äpfel (säuerlich)", None)
+        );
+    }
+
+    #[test]
+    fn test_rules_with_overrides() {
+        let (rules, rejected) =
+            ScalingRules::parse("# eigene\nSalz: linear\nLorbeerblätter: fix\n");
+        assert!(rejected.is_empty());
+        let aliases = Aliases::parse("Ei: Eier\n");
+
+        assert_eq!(rules.get("Salz", &aliases), Some(Scaling::Linear));
+        assert_eq!(rules.get("Lorbeerblätter", &aliases), Some(Scaling::Fixed));
+        assert_eq!(rules.get("Eier", &aliases), Some(Scaling::Whole));
+        assert_eq!(
+            rules.get("pfeffer", &aliases),
+            Some(Scaling::Sublinear(SPICE_EXPONENT))
+        );
+        assert_eq!(rules.get("Mehl", &aliases), None);
+    }
+
+    #[test]
+    fn test_rules_report_rejected_lines() {
+        let (rules, rejected) =
+            ScalingRules::parse("# Regeln\n- Zwiebel: gnaz\n\nMuskat fix\n- Ei: ganz\n");
+
+        assert_eq!(rules.get("Zwiebel", &Aliases::default()), None);
+        assert_eq!(rules.get("Ei", &Aliases::default()), Some(Scaling::Whole));
+        let rejected: Vec<String> = rejected.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rejected,
+            [
+                "2:1: unknown scaling rule 'Zwiebel: gnaz', ignoring it",
+                "4:1: unknown scaling rule 'Muskat fix', ignoring it",
+            ]
+        );
+    }
+}
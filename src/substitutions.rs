@@ -0,0 +1,89 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+
+/// Vegan replacements used when no substitution file overrides them.
+const BUILTIN: &[(&str, &str)] = &[
+    ("Butter", "Vegane Butter"),
+    ("Milch", "Hafermilch"),
+    ("Sahne", "Hafersahne"),
+    ("Schlagsahne", "Vegane Schlagsahne"),
+    ("Joghurt", "Sojajoghurt"),
+    ("Quark", "Sojaquark"),
+    ("Feta", "Feta vegan"),
+    ("Käse", "Veganer Käse"),
+    ("Frischkäse", "Veganer Frischkäse"),
+    ("Honig", "Agavendicksaft"),
+    ("Hackfleisch", "Sojahack"),
+    ("Wurst", "Wurstalternative"),
+];
+
+/// Table of vegan substitutes for single ingredients.
+///
+/// Read from a file with one `Original: Ersatz` pair per line, entries
+/// override the built-in table. Empty lines and lines starting with `#` are
+/// ignored.
+#[derive(Debug)]
+pub(crate) struct Substitutions(HashMap<String, String>);
+
+impl Default for Substitutions {
+    fn default() -> Self {
+        Self(
+            BUILTIN
+                .iter()
+                .map(|(original, substitute)| (original.to_lowercase(), substitute.to_string()))
+                .collect(),
+        )
+    }
+}
+
+impl Substitutions {
+    /// Load the table, a missing file yields the built-in table.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read substitution file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut substitutions = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim().trim_start_matches("- ");
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((original, substitute)) = line.split_once(':') {
+                substitutions.0.insert(
+                    original.trim().to_lowercase(),
+                    substitute.trim().to_string(),
+                );
+            }
+        }
+
+        substitutions
+    }
+
+    /// The vegan substitute of an ingredient, if there is one.
+    pub(crate) fn get(&self, ingredient: &str) -> Option<&str> {
+        self.0.get(&ingredient.to_lowercase()).map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_and_overrides() {
+        let substitutions = Substitutions::parse("# eigene\nmilch: Sojamilch\nEier: Eiersatz\n");
+
+        assert_eq!(substitutions.get("Milch"), Some("Sojamilch"));
+        assert_eq!(substitutions.get("Eier"), Some("Eiersatz"));
+        assert_eq!(substitutions.get("Butter"), Some("Vegane Butter"));
+        assert_eq!(substitutions.get("Kokosmilch"), None);
+    }
+}
@@ -0,0 +1,287 @@
+use std::sync::LazyLock;
+
+use chrono::NaiveDate;
+use regex::Regex;
+
+use crate::{diagnostics::Position, groups};
+
+/// Items of a table cell: dish references, shopping markers and plain text.
+static ENTRY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[\[([^\]]+)\]\](?:\s*\((\d+)\))?|⟨([^⟩]*)⟩|([^,\[⟨]+)").unwrap()
+});
+
+/// A head count suffix like `Freitag (40)`.
+static COUNT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(.*?)\s*\((\d+)\)$").unwrap());
+
+/// A plan written as a markdown table with meal rows and day columns.
+///
+/// ```text
+/// Personen: 43
+/// Starttag: 2026-04-02
+///
+/// |                     | Donnerstag           | Freitag (40)    |
+/// | ------------------- | -------------------- | --------------- |
+/// | **Frühstück**       |                      | Standard        |
+/// | **Abendessen** (38) | Maultaschen in Brühe | [[Curry]](20)   |
+/// ```
+#[derive(Debug, Default)]
+pub(crate) struct TablePlan {
+    pub(crate) people: Option<usize>,
+    /// Participant groups like `vegan 12, vegetarisch 8` and their position.
+    pub(crate) groups: Option<(String, Position)>,
+    pub(crate) start: Option<NaiveDate>,
+    /// Day columns.
+    pub(crate) days: Vec<Header>,
+    /// Meal rows.
+    pub(crate) slots: Vec<Row>,
+}
+
+/// A day column or meal row header with its optional head count.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Header {
+    pub(crate) name: String,
+    pub(crate) people: Option<usize>,
+    pub(crate) at: Position,
+}
+
+/// A meal row with the entries of each day column.
+#[derive(Debug)]
+pub(crate) struct Row {
+    pub(crate) header: Header,
+    pub(crate) cells: Vec<Vec<Entry>>,
+}
+
+/// A single item of a table cell.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Entry {
+    /// A dish reference like `[[Curry|vegan 4]](20)`, without brackets.
+    Dish {
+        reference: String,
+        people: Option<usize>,
+        at: Position,
+    },
+    /// A shopping marker like `⟨Einkauf⟩`, without brackets.
+    Marker { text: String, at: Position },
+    /// Plain text such as "Maultaschen in Brühe".
+    Text { text: String, at: Position },
+}
+
+/// Whether a plan is written in the table format.
+pub(crate) fn is_table(content: &str) -> bool {
+    content
+        .lines()
+        .any(|line| line.trim_start().starts_with('|'))
+}
+
+pub(crate) fn parse(content: &str) -> TablePlan {
+    let mut plan = TablePlan::default();
+    let mut header_seen = false;
+
+    for (row, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(count) = trimmed.strip_prefix("Personen:") {
+            let (count, groups) = groups::split_persons(count);
+            plan.people = count.parse().ok();
+            plan.groups = groups.map(|groups| {
+                let column = line.rfind(groups).unwrap_or_default() + 1;
+                let at = Position {
+                    line: row + 1,
+                    column,
+                };
+                (groups.to_string(), at)
+            });
+            continue;
+        }
+        if let Some(date) = trimmed.strip_prefix("Starttag:") {
+            plan.start = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok();
+            continue;
+        }
+        if !trimmed.starts_with('|') {
+            continue;
+        }
+
+        let cells = split_row(line, row + 1);
+        if cells.iter().all(|(cell, _)| is_separator(cell)) {
+            continue;
+        }
+        let Some(((first, first_at), rest)) = cells.split_first() else {
+            continue;
+        };
+
+        if !header_seen {
+            header_seen = true;
+            plan.days = rest.iter().map(|(cell, at)| header(cell, *at)).collect();
+            continue;
+        }
+        plan.slots.push(Row {
+            header: header(first, *first_at),
+            cells: rest.iter().map(|(cell, at)| entries(cell, *at)).collect(),
+        });
+    }
+
+    plan
+}
+
+/// Cells of a table row with the position of their text.
+///
+/// Escaped pipes like `[[Curry\\|vegan 4]]` stay inside the cell.
+fn split_row(line: &str, line_number: usize) -> Vec<(String, Position)> {
+    let line = line.trim_end();
+    let mut bounds = Vec::new();
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        if c == '|' && !escaped {
+            bounds.push(i);
+        }
+        escaped = c == '\\';
+    }
+
+    bounds
+        .windows(2)
+        .map(|window| {
+            let cell = &line[window[0] + 1..window[1]];
+            let indent = cell.len() - cell.trim_start().len();
+            (
+                cell.trim().replace("\\|", "|"),
+                Position {
+                    line: line_number,
+                    column: window[0] + 1 + indent + 1,
+                },
+            )
+        })
+        .collect()
+}
+
+fn is_separator(cell: &str) -> bool {
+    !cell.is_empty() && cell.chars().all(|c| matches!(c, '-' | ':' | ' '))
+}
+
+/// A header cell like `**Abendessen** (38)`, emphasis is dropped.
+fn header(cell: &str, at: Position) -> Header {
+    let cell = cell.replace("**", "");
+    let cell = cell.trim();
+    match COUNT.captures(cell) {
+        Some(captures) => Header {
+            name: captures[1].to_string(),
+            people: captures[2].parse().ok(),
+            at,
+        },
+        None => Header {
+            name: cell.to_string(),
+            people: None,
+            at,
+        },
+    }
+}
+
+/// Items of a cell, separated by commas or line breaks.
+fn entries(cell: &str, at: Position) -> Vec<Entry> {
+    let cell = cell.replace("<br>", ",");
+    let position = |start: usize| Position {
+        line: at.line,
+        column: at.column + start,
+    };
+
+    ENTRY
+        .captures_iter(&cell)
+        .filter_map(|captures| {
+            let start = captures.get(0)?.start();
+            if let Some(reference) = captures.get(1) {
+                return Some(Entry::Dish {
+                    reference: reference.as_str().to_string(),
+                    people: captures
+                        .get(2)
+                        .and_then(|count| count.as_str().parse().ok()),
+                    at: position(start),
+                });
+            }
+            if let Some(marker) = captures.get(3) {
+                return Some(Entry::Marker {
+                    text: marker.as_str().trim().to_string(),
+                    at: position(start),
+                });
+            }
+            let text = captures.get(4)?.as_str();
+            let indent = text.len() - text.trim_start().len();
+            (!text.trim().is_empty()).then(|| Entry::Text {
+                text: text.trim().to_string(),
+                at: position(start + indent),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+
+    #[test]
+    fn test_parse_test_data_table() {
+        let content = include_str!("test-data/table.md");
+        assert!(is_table(content));
+
+        let plan = parse(content);
+        assert_eq!(plan.people, None);
+        assert_eq!(
+            plan.days
+                .iter()
+                .map(|day| day.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Donnerstag", "Freitag"]
+        );
+        assert_eq!(
+            plan.slots
+                .iter()
+                .map(|slot| slot.header.name.as_str())
+                .collect::<Vec<_>>(),
+            ["Frühstück", "Mittagessen", "Kaffee & Kuchen", "Abendessen"]
+        );
+
+        assert!(plan.slots[0].cells[0].is_empty());
+        assert_eq!(
+            plan.slots[1].cells[1],
+            [Entry::Dish {
+                reference: "Nudeln Mit Tomatensoße".to_string(),
+                people: None,
+                at: at(4, 48),
+            }]
+        );
+        assert_eq!(
+            plan.slots[3].cells[0],
+            [Entry::Text {
+                text: "Maultaschen in Brühe".to_string(),
+                at: at(6, 25),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_counts_and_markers() {
+        let content = "Personen: 43 (vegan 12)\nStarttag: 2026-04-02\n\n\
+            | | Donnerstag (40) |\n\
+            |---|---|\n\
+            | **Abendessen** (38) | ⟨Einkauf⟩ [[Joghurt mit Nüssen, Honig]](12), Brot <br> [[Curry\\|vegan 4]] |\n";
+
+        let plan = parse(content);
+        assert_eq!(plan.people, Some(43));
+        assert_eq!(plan.groups, Some(("vegan 12".to_string(), at(1, 15))));
+        assert_eq!(plan.start, NaiveDate::from_ymd_opt(2026, 4, 2));
+        assert_eq!(plan.days[0].name, "Donnerstag");
+        assert_eq!(plan.days[0].people, Some(40));
+        assert_eq!(plan.slots[0].header.name, "Abendessen");
+        assert_eq!(plan.slots[0].header.people, Some(38));
+
+        let cell = &plan.slots[0].cells[0];
+        assert_eq!(cell.len(), 4);
+        assert!(matches!(&cell[0], Entry::Marker { text, .. } if text == "Einkauf"));
+        assert!(matches!(
+            &cell[1],
+            Entry::Dish { reference, people: Some(12), .. } if reference == "Joghurt mit Nüssen, Honig"
+        ));
+        assert!(matches!(&cell[2], Entry::Text { text, .. } if text == "Brot"));
+    }
+}
@@ -0,0 +1,326 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{
+    categories::Categories,
+    dish::Dish,
+    extras::Extras,
+    plan::{parse_weekday, weekday_name},
+    types::{Ingredient, IngredientList},
+};
+
+/// Marker text of an unnamed trip.
+const UNNAMED: &str = "Einkauf";
+
+/// Days the items of a store section keep after buying them.
+const SHELF_LIFE: &[(&str, i64)] = &[("Frisches", 4), ("Kühlregal", 5)];
+
+/// Short German weekday names as used in markers like `⟨Großmarkt Mi⟩`.
+const SHORT_WEEKDAYS: &[(&str, Weekday)] = &[
+    ("Mo", Weekday::Mon),
+    ("Di", Weekday::Tue),
+    ("Mi", Weekday::Wed),
+    ("Do", Weekday::Thu),
+    ("Fr", Weekday::Fri),
+    ("Sa", Weekday::Sat),
+    ("So", Weekday::Sun),
+];
+
+/// A shopping marker like `⟨Großmarkt Mi⟩` in a day of the plan.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct Marker {
+    /// Index of the first dish of the day bought on this trip.
+    pub(crate) index: usize,
+    /// Marker text, empty for an unnamed `⟨Einkauf⟩`.
+    pub(crate) label: String,
+    /// Day of the purchase, if the marker names one.
+    pub(crate) date: Option<NaiveDate>,
+}
+
+impl Marker {
+    /// Read the marker text of a day's marker.
+    ///
+    /// A trailing weekday like "Mi" is resolved to the closest such day around
+    /// `day`, dates may be written as "01.04.", "01.04.2026" or "2026-04-01".
+    pub(crate) fn parse(text: &str, index: usize, day: NaiveDate) -> Self {
+        let text = text
+            .trim()
+            .trim_start_matches('⟨')
+            .trim_end_matches('⟩')
+            .trim();
+        let date = text
+            .rsplit(char::is_whitespace)
+            .next()
+            .and_then(|word| parse_date(word, day));
+        let label = if text.eq_ignore_ascii_case(UNNAMED) {
+            String::new()
+        } else {
+            text.to_string()
+        };
+
+        Self { index, label, date }
+    }
+}
+
+/// A purchase with the dishes it covers.
+pub(crate) struct ShoppingTrip {
+    /// Heading of the trip, e.g. "Großmarkt Mi" or "Einkauf 2".
+    pub(crate) label: String,
+    /// Day of the purchase, from the marker or the day it is written on.
+    pub(crate) date: NaiveDate,
+    /// Whether the date was given in the marker.
+    pub(crate) explicit: bool,
+    /// Scaled ingredients of all covered dishes.
+    pub(crate) list: IngredientList,
+    /// Lines of extra lists and recipes that could not be read as items.
+    pub(crate) extras: Vec<String>,
+    /// Amounts taken from the storeroom instead of buying them.
+    pub(crate) stocked: Vec<Ingredient>,
+    /// Day, ingredient and dish of every use, for freshness checks.
+    uses: Vec<(NaiveDate, String, String)>,
+    dishes: usize,
+}
+
+impl ShoppingTrip {
+    /// An empty trip starting with a marker written on `day`.
+    pub(crate) fn new(marker: &Marker, day: NaiveDate) -> Self {
+        Self {
+            label: marker.label.clone(),
+            date: marker.date.unwrap_or(day),
+            explicit: marker.date.is_some(),
+            list: IngredientList::new(),
+            extras: Vec::new(),
+            stocked: Vec::new(),
+            uses: Vec::new(),
+            dishes: 0,
+        }
+    }
+
+    /// Add the shopping list of a dish cooked on `day`.
+    pub(crate) fn add(&mut self, dish: &Dish, day: NaiveDate) {
+        let items = dish.shopping_list();
+        self.uses.extend(
+            items
+                .iter()
+                .map(|item| (day, item.name.clone(), item.dish.clone())),
+        );
+        self.list.0.extend(items);
+        // Unreadable recipe lines have to be bought by hand.
+        self.extras.extend(dish.gaps.iter().map(|gap| {
+            format!(
+                "- [ ] {} ({}, Zeile {} nicht gelesen)",
+                gap.text, dish.name, gap.line
+            )
+        }));
+        self.dishes += 1;
+    }
+
+    /// Add the items of a hand-written list.
+    pub(crate) fn merge(&mut self, extras: Extras) {
+        self.list.0.extend(extras.items);
+        self.extras.extend(extras.verbatim);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.dishes == 0
+    }
+
+    /// Items used later than their store section keeps, the latest use per item.
+    pub(crate) fn perishables(&self, categories: &Categories) -> Vec<Perishable> {
+        let mut perishables: Vec<Perishable> = Vec::new();
+
+        for (day, name, dish) in &self.uses {
+            let section = categories.section(name);
+            let Some((_, keeps)) = SHELF_LIFE.iter().find(|(s, _)| *s == section) else {
+                continue;
+            };
+            let days = (*day - self.date).num_days();
+            if days <= *keeps {
+                continue;
+            }
+            match perishables.iter_mut().find(|p| p.name == *name) {
+                Some(perishable) if perishable.days >= days => {}
+                Some(perishable) => {
+                    perishable.days = days;
+                    perishable.day = *day;
+                    perishable.dish = dish.clone();
+                }
+                None => perishables.push(Perishable {
+                    name: name.clone(),
+                    dish: dish.clone(),
+                    day: *day,
+                    days,
+                    section: section.to_string(),
+                    keeps: *keeps,
+                }),
+            }
+        }
+
+        perishables
+    }
+
+    /// Line with the day of the purchase, if the marker names one.
+    pub(crate) fn date_line(&self) -> Option<String> {
+        self.explicit
+            .then(|| format!("**Einkauf am {}**", label(self.date)))
+    }
+
+    /// Block warning about perishable items, empty if there are none.
+    pub(crate) fn perishables_as_markdown(&self, categories: &Categories) -> String {
+        let perishables = self.perishables(categories);
+        if perishables.is_empty() {
+            return String::new();
+        }
+        let items: Vec<String> = perishables
+            .iter()
+            .map(|p| {
+                format!(
+                    "- {} für {} am {}: {} Tage nach dem Einkauf ({} hält etwa {} Tage)",
+                    p.name,
+                    p.dish,
+                    label(p.day),
+                    p.days,
+                    p.section,
+                    p.keeps
+                )
+            })
+            .collect();
+        format!("### Haltbarkeit\n{}", items.join("\n"))
+    }
+}
+
+/// An item that has to keep longer than its section usually does.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Perishable {
+    pub(crate) name: String,
+    pub(crate) dish: String,
+    /// Day of the latest use.
+    pub(crate) day: NaiveDate,
+    /// Days between purchase and use.
+    pub(crate) days: i64,
+    pub(crate) section: String,
+    /// Days items of the section keep.
+    pub(crate) keeps: i64,
+}
+
+/// Number the unnamed trips and drop those without dishes.
+pub(crate) fn finish(trips: Vec<ShoppingTrip>) -> Vec<ShoppingTrip> {
+    let mut trips: Vec<ShoppingTrip> = if trips.iter().all(ShoppingTrip::is_empty) {
+        trips.into_iter().take(1).collect()
+    } else {
+        trips.into_iter().filter(|trip| !trip.is_empty()).collect()
+    };
+    for (i, trip) in trips.iter_mut().enumerate() {
+        if trip.label.is_empty() {
+            trip.label = format!("{} {}", UNNAMED, i + 1);
+        }
+    }
+    trips
+}
+
+/// Weekday and date, e.g. "Mittwoch, 01.04.2026".
+fn label(date: NaiveDate) -> String {
+    format!(
+        "{}, {}",
+        weekday_name(date.weekday()),
+        date.format("%d.%m.%Y")
+    )
+}
+
+/// A date or weekday written in a marker, relative to the marker's day.
+fn parse_date(word: &str, day: NaiveDate) -> Option<NaiveDate> {
+    if let Ok(date) = NaiveDate::parse_from_str(word, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(word, "%d.%m.%Y") {
+        return Some(date);
+    }
+    if let Some((d, m)) = word.strip_suffix('.').and_then(|w| w.split_once('.')) {
+        return NaiveDate::from_ymd_opt(day.year(), m.parse().ok()?, d.parse().ok()?);
+    }
+
+    let weekday = SHORT_WEEKDAYS
+        .iter()
+        .find(|(short, _)| short.eq_ignore_ascii_case(word.trim_end_matches('.')))
+        .map(|(_, weekday)| *weekday)
+        .or_else(|| parse_weekday(word))?;
+    let ahead = (weekday.num_days_from_monday() as i64
+        - day.weekday().num_days_from_monday() as i64)
+        .rem_euclid(7);
+    let offset = if ahead <= 3 { ahead } else { ahead - 7 };
+    Some(day + chrono::Duration::days(offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 4, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse_marker() {
+        // 02.04.2026 is a Thursday.
+        let marker = Marker::parse("⟨Großmarkt Mi⟩", 2, date(2));
+        assert_eq!(marker.label, "Großmarkt Mi");
+        assert_eq!(marker.date, Some(date(1)));
+        assert_eq!(marker.index, 2);
+
+        assert_eq!(Marker::parse("Markt Sa", 0, date(2)).date, Some(date(4)));
+        assert_eq!(Marker::parse("Aldi 06.04.", 0, date(2)).date, Some(date(6)));
+        assert_eq!(
+            Marker::parse("Aldi 2026-04-07", 0, date(2)).date,
+            Some(date(7))
+        );
+        assert_eq!(Marker::parse("Bäcker", 0, date(2)).date, None);
+
+        let unnamed = Marker::parse("Einkauf", 0, date(2));
+        assert!(unnamed.label.is_empty());
+    }
+
+    #[test]
+    fn test_perishables_and_labels() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"2 Personen\n\n## Zutaten\n- 1 Kopf Salat\n- 500 g Nudeln\n")
+            .unwrap();
+        let dish = Dish::from_file(file.path(), "Salatteller", 2).unwrap();
+
+        let marker = Marker::parse("Großmarkt Mi", 0, date(2));
+        let mut trip = ShoppingTrip::new(&marker, date(2));
+        trip.add(&dish, date(3));
+        trip.add(&dish, date(7));
+
+        let categories = Categories::default();
+        assert_eq!(
+            trip.perishables(&categories),
+            [Perishable {
+                name: "Kopf Salat".to_string(),
+                dish: "Salatteller".to_string(),
+                day: date(7),
+                days: 6,
+                section: "Frisches".to_string(),
+                keeps: 4,
+            }]
+        );
+        assert_eq!(
+            trip.date_line().as_deref(),
+            Some("**Einkauf am Mittwoch, 01.04.2026**")
+        );
+        assert_eq!(
+            trip.perishables_as_markdown(&categories),
+            "### Haltbarkeit\n\
+             - Kopf Salat für Salatteller am Dienstag, 07.04.2026: 6 Tage nach dem Einkauf \
+             (Frisches hält etwa 4 Tage)"
+        );
+
+        let unnamed = ShoppingTrip::new(&Marker::default(), date(2));
+        let trips = finish(vec![unnamed, trip]);
+        assert_eq!(trips.len(), 1);
+        assert_eq!(trips[0].label, "Großmarkt Mi");
+
+        let trips = finish(vec![ShoppingTrip::new(&Marker::default(), date(2))]);
+        assert_eq!(trips[0].label, "Einkauf 1");
+    }
+}
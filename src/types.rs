@@ -1,5 +1,12 @@
 use std::collections::HashMap;
 
+use crate::{
+    categories::Categories,
+    packages::Packed,
+    quantity::Quantity,
+    units::{Family, Unit},
+};
+
 pub(crate) type Ingredients = Vec<Ingredient>;
 
 /// A single ingredient
@@ -13,10 +20,32 @@ pub(crate) struct Ingredient {
     pub(crate) name: String,
     /// The dish this ingredient is from.
     pub(crate) dish: String,
+    /// Whole packages to buy, set after rounding an accumulated list.
+    pub(crate) packed: Option<Packed>,
+    /// Quantity as written in the recipe, scaled along with the amount.
+    pub(crate) quantity: Option<Quantity>,
 }
 
 pub(crate) struct IngredientList(pub(crate) Ingredients);
 
+/// What ingredients are grouped by when accumulating.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum MeasureKey {
+    /// A known unit, convertible within its family.
+    Family(Family),
+    /// Any other measure, kept as written.
+    Raw(String),
+}
+
+impl MeasureKey {
+    fn of(measure: &str) -> Self {
+        match Unit::parse(measure) {
+            Some(unit) => MeasureKey::Family(unit.family),
+            None => MeasureKey::Raw(measure.to_string()),
+        }
+    }
+}
+
 impl IngredientList {
     pub(crate) fn new() -> Self {
         Self(Vec::new())
@@ -27,25 +56,51 @@ impl IngredientList {
     }
 
     /// Accumulate ingredients by name and unit.
+    ///
+    /// Known units of the same family are converted and merged, the total is
+    /// expressed in a readable unit of that family. Unknown measures are only
+    /// merged if they are spelled the same.
     pub(crate) fn accumulate(&mut self) {
-        let mut grouped: HashMap<(String, String), Vec<Ingredient>> = HashMap::new();
+        let mut grouped: HashMap<(String, MeasureKey), Vec<Ingredient>> = HashMap::new();
 
         for ingredient in self.0.drain(..) {
-            let key = (ingredient.name.clone(), ingredient.measure.clone());
-            grouped.entry(key).or_insert_with(Vec::new).push(ingredient);
+            let key = (ingredient.name.clone(), MeasureKey::of(&ingredient.measure));
+            grouped.entry(key).or_default().push(ingredient);
         }
 
         self.0 = grouped
             .into_iter()
-            .map(|((name, measure), ingredients)| {
-                let total_amount: f32 = ingredients.iter().map(|i| i.amount).sum();
+            .map(|((name, key), mut ingredients)| {
+                // Keep the packages of already rounded entries.
+                let packed = match ingredients.as_mut_slice() {
+                    [single] => single.packed.take(),
+                    _ => None,
+                };
                 let dishes: Vec<String> = ingredients.iter().map(|i| i.dish.clone()).collect();
+                let (amount, measure) = match key {
+                    MeasureKey::Family(family) => {
+                        let base_amount: f32 = ingredients
+                            .iter()
+                            .map(|i| {
+                                let factor = Unit::parse(&i.measure).map_or(1.0, |u| u.factor);
+                                i.amount * factor
+                            })
+                            .sum();
+                        let (amount, symbol) = family.readable(base_amount);
+                        (amount, symbol.to_string())
+                    }
+                    MeasureKey::Raw(measure) => {
+                        (ingredients.iter().map(|i| i.amount).sum(), measure)
+                    }
+                };
 
                 Ingredient {
-                    amount: total_amount,
-                    measure: measure.clone(),
-                    name: name.clone(),
+                    amount,
+                    measure,
+                    name,
                     dish: dishes.join(", "),
+                    packed,
+                    quantity: None,
                 }
             })
             .collect();
@@ -55,34 +110,67 @@ impl IngredientList {
     pub(crate) fn as_md_list(&mut self) -> String {
         self.accumulate();
 
-        let mut items: Vec<String> = self
-            .0
-            .iter()
-            .map(|ingredient| {
-                let amount_str = if ingredient.measure.is_empty() {
-                    format!("{:.1}", ingredient.amount)
-                } else {
-                    format!("{:.1} {}", ingredient.amount, ingredient.measure)
-                };
-
-                format!(
-                    "- [ ] {}: {} ({})",
-                    ingredient.name, amount_str, ingredient.dish
-                )
-            })
-            .collect();
+        let mut items: Vec<String> = self.0.iter().map(md_item).collect();
 
         items.sort();
         items.join("\n")
     }
 
-    /// Generate clustered md shopping list with AI.
-    pub(crate) fn as_clustered_md_list(&mut self) -> String {
-        // For now, just use the same implementation as as_md_list
-        self.as_md_list()
+    /// Generate md shopping list grouped by store section.
+    pub(crate) fn as_clustered_md_list(&mut self, categories: &Categories) -> String {
+        self.accumulate();
+
+        let mut sections: HashMap<&str, Vec<String>> = HashMap::new();
+        for ingredient in &self.0 {
+            sections
+                .entry(categories.section(&ingredient.name))
+                .or_default()
+                .push(md_item(ingredient));
+        }
+
+        let mut output = Vec::new();
+        for name in categories.order() {
+            if let Some(mut items) = sections.remove(name) {
+                items.sort();
+                output.push(format!("### {}\n{}", name, items.join("\n")));
+            }
+        }
+        output.join("\n\n")
     }
 }
 
+/// A single unchecked shopping list entry.
+fn md_item(ingredient: &Ingredient) -> String {
+    let amount_str = if ingredient.measure.is_empty() {
+        format!("{:.1}", ingredient.amount)
+    } else {
+        format!("{:.1} {}", ingredient.amount, ingredient.measure)
+    };
+    let amount_str = match &ingredient.packed {
+        Some(packed) if packed.measure.is_empty() => {
+            format!(
+                "{} × {} (benötigt {})",
+                packed.count, packed.size, amount_str
+            )
+        }
+        Some(packed) => format!(
+            "{} × {} {} (benötigt {})",
+            packed.count, packed.size, packed.measure, amount_str
+        ),
+        None => amount_str,
+    };
+
+    format!(
+        "- [ ] {}: {} ({})",
+        ingredient.name, amount_str, ingredient.dish
+    )
+}
+
+/// A ticked off shopping list entry.
+pub(crate) fn checked_md_item(ingredient: &Ingredient) -> String {
+    md_item(ingredient).replacen("- [ ]", "- [x]", 1)
+}
+
 impl Clone for Ingredient {
     fn clone(&self) -> Self {
         Self {
@@ -90,6 +178,8 @@ impl Clone for Ingredient {
             measure: self.measure.clone(),
             name: self.name.clone(),
             dish: self.dish.clone(),
+            packed: self.packed.clone(),
+            quantity: self.quantity.clone(),
         }
     }
 }
@@ -112,18 +202,24 @@ mod tests {
                 measure: "g".to_string(),
                 name: "flour".to_string(),
                 dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 200.0,
                 measure: "g".to_string(),
                 name: "flour".to_string(),
                 dish: "Dish B".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 50.0,
                 measure: "g".to_string(),
                 name: "flour".to_string(),
                 dish: "Dish C".to_string(),
+                packed: None,
+                quantity: None,
             },
         ]);
 
@@ -146,18 +242,24 @@ mod tests {
                 measure: "cups".to_string(),
                 name: "sugar".to_string(),
                 dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 100.0,
                 measure: "g".to_string(),
                 name: "sugar".to_string(),
                 dish: "Dish B".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 1.0,
                 measure: "cups".to_string(),
                 name: "sugar".to_string(),
                 dish: "Dish C".to_string(),
+                packed: None,
+                quantity: None,
             },
         ]);
 
@@ -185,24 +287,32 @@ mod tests {
                 measure: "g".to_string(),
                 name: "flour".to_string(),
                 dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 150.0,
                 measure: "g".to_string(),
                 name: "sugar".to_string(),
                 dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 100.0,
                 measure: "g".to_string(),
                 name: "butter".to_string(),
                 dish: "Dish B".to_string(),
+                packed: None,
+                quantity: None,
             },
             Ingredient {
                 amount: 50.0,
                 measure: "g".to_string(),
                 name: "flour".to_string(),
                 dish: "Dish C".to_string(),
+                packed: None,
+                quantity: None,
             },
         ]);
 
@@ -226,4 +336,151 @@ mod tests {
         assert_eq!(butter.measure, "g");
         assert_eq!(butter.dish, "Dish B");
     }
+
+    #[test]
+    fn test_accumulate_converts_compatible_units() {
+        let mut list = IngredientList::from(vec![
+            Ingredient {
+                amount: 0.6,
+                measure: "kg".to_string(),
+                name: "Weintrauben".to_string(),
+                dish: "Obstsalat".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 500.0,
+                measure: "g".to_string(),
+                name: "Weintrauben".to_string(),
+                dish: "Obst + Kekse".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 1300.0,
+                measure: "G".to_string(),
+                name: "Weintrauben".to_string(),
+                dish: "Obstsalat".to_string(),
+                packed: None,
+                quantity: None,
+            },
+        ]);
+
+        list.accumulate();
+
+        assert_eq!(list.0.len(), 1);
+        assert!((list.0[0].amount - 2.4).abs() < 1e-4);
+        assert_eq!(list.0[0].measure, "kg");
+        assert!(list.0[0].dish.contains("Obst + Kekse"));
+    }
+
+    #[test]
+    fn test_accumulate_merges_unit_spellings() {
+        let mut list = IngredientList::from(vec![
+            Ingredient {
+                amount: 3.0,
+                measure: "Stk".to_string(),
+                name: "Zitronen".to_string(),
+                dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 2.0,
+                measure: "Stück".to_string(),
+                name: "Zitronen".to_string(),
+                dish: "Dish B".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 1.0,
+                measure: "EL".to_string(),
+                name: "Zucker".to_string(),
+                dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 3.0,
+                measure: "TL".to_string(),
+                name: "Zucker".to_string(),
+                dish: "Dish B".to_string(),
+                packed: None,
+                quantity: None,
+            },
+        ]);
+
+        list.accumulate();
+
+        assert_eq!(list.0.len(), 2);
+
+        let lemons = list.0.iter().find(|i| i.name == "Zitronen").unwrap();
+        assert_eq!(lemons.amount, 5.0);
+        assert_eq!(lemons.measure, "Stück");
+
+        let sugar = list.0.iter().find(|i| i.name == "Zucker").unwrap();
+        assert_eq!(sugar.amount, 2.0);
+        assert_eq!(sugar.measure, "EL");
+    }
+
+    #[test]
+    fn test_as_clustered_md_list_groups_by_section() {
+        let mut list = IngredientList::from(vec![
+            Ingredient {
+                amount: 200.0,
+                measure: "g".to_string(),
+                name: "Butter".to_string(),
+                dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 2.0,
+                measure: "".to_string(),
+                name: "Zwiebel".to_string(),
+                dish: "Dish A".to_string(),
+                packed: None,
+                quantity: None,
+            },
+            Ingredient {
+                amount: 1.0,
+                measure: "".to_string(),
+                name: "Seitan".to_string(),
+                dish: "Dish B".to_string(),
+                packed: None,
+                quantity: None,
+            },
+        ]);
+
+        let md = list.as_clustered_md_list(&Categories::default());
+
+        assert_eq!(
+            md,
+            "### Frisches\n- [ ] Zwiebel: 2.0 (Dish A)\n\n\
+             ### Kühlregal\n- [ ] Butter: 200.0 g (Dish A)\n\n\
+             ### Unsortiert\n- [ ] Seitan: 1.0 (Dish B)"
+        );
+    }
+
+    #[test]
+    fn test_md_list_shows_packages() {
+        let mut list = IngredientList::from(vec![Ingredient {
+            amount: 1.1,
+            measure: "kg".to_string(),
+            name: "Butter".to_string(),
+            dish: "Kuchen".to_string(),
+            packed: Some(Packed {
+                count: 5,
+                size: 250.0,
+                measure: "g".to_string(),
+            }),
+            quantity: None,
+        }]);
+
+        assert_eq!(
+            list.as_md_list(),
+            "- [ ] Butter: 5 × 250 g (benötigt 1.1 kg) (Kuchen)"
+        );
+    }
 }
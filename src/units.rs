@@ -0,0 +1,340 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+
+/// Group of units that can be converted into each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Family {
+    /// Base unit: g
+    Mass,
+    /// Base unit: ml
+    Volume,
+    /// Base unit: TL
+    Spoon,
+    /// Base unit: Stück
+    Count,
+}
+
+/// A known unit and how it relates to the base unit of its family.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Unit {
+    pub(crate) family: Family,
+    /// Canonical spelling of the unit.
+    pub(crate) symbol: &'static str,
+    /// Factor to convert an amount of this unit into the family's base unit.
+    pub(crate) factor: f32,
+}
+
+const fn unit(family: Family, symbol: &'static str, factor: f32) -> Unit {
+    Unit {
+        family,
+        symbol,
+        factor,
+    }
+}
+
+const MILLIGRAM: Unit = unit(Family::Mass, "mg", 0.001);
+const GRAM: Unit = unit(Family::Mass, "g", 1.0);
+const KILOGRAM: Unit = unit(Family::Mass, "kg", 1000.0);
+const MILLILITER: Unit = unit(Family::Volume, "ml", 1.0);
+const LITER: Unit = unit(Family::Volume, "l", 1000.0);
+const TEASPOON: Unit = unit(Family::Spoon, "TL", 1.0);
+const TABLESPOON: Unit = unit(Family::Spoon, "EL", 3.0);
+const PIECE: Unit = unit(Family::Count, "Stück", 1.0);
+
+impl Unit {
+    /// Look up a measure as written in a recipe.
+    pub(crate) fn parse(measure: &str) -> Option<Unit> {
+        UNIT_NAMES
+            .iter()
+            .find(|(singular, plural, abbreviations, _)| {
+                spelled(measure, singular, plural, abbreviations)
+            })
+            .and_then(|(_, _, _, unit)| *unit)
+    }
+}
+
+/// Convert an amount between two measures.
+///
+/// Known units are converted within their family, an empty measure counts as
+/// pieces. Unknown measures only match if they are spelled the same.
+pub(crate) fn convert(amount: f32, from: &str, to: &str) -> Option<f32> {
+    let unit = |measure: &str| {
+        if measure.trim().is_empty() {
+            Some(PIECE)
+        } else {
+            Unit::parse(measure)
+        }
+    };
+    match (unit(from), unit(to)) {
+        (Some(from), Some(to)) if from.family == to.family => {
+            Some(amount * from.factor / to.factor)
+        }
+        _ if from.trim().eq_ignore_ascii_case(to.trim()) => Some(amount),
+        _ => None,
+    }
+}
+
+impl Family {
+    /// Pick a readable unit for an amount given in the base unit.
+    pub(crate) fn readable(self, base_amount: f32) -> (f32, &'static str) {
+        let unit = match self {
+            Family::Mass if base_amount >= KILOGRAM.factor => KILOGRAM,
+            Family::Mass if base_amount > 0.0 && base_amount < GRAM.factor => MILLIGRAM,
+            Family::Mass => GRAM,
+            Family::Volume if base_amount >= LITER.factor => LITER,
+            Family::Volume => MILLILITER,
+            Family::Spoon if base_amount >= TABLESPOON.factor => TABLESPOON,
+            Family::Spoon => TEASPOON,
+            Family::Count => PIECE,
+        };
+        (base_amount / unit.factor, unit.symbol)
+    }
+}
+
+/// Units recognized without a registry file, as singular, plural and
+/// abbreviations, with the measure they convert as.
+const UNIT_NAMES: &[(&str, &str, &[&str], Option<Unit>)] = &[
+    ("Milligramm", "Milligramm", &["mg"], Some(MILLIGRAM)),
+    ("Gramm", "Gramm", &["g"], Some(GRAM)),
+    ("Kilogramm", "Kilogramm", &["kg", "Kilo"], Some(KILOGRAM)),
+    ("Milliliter", "Milliliter", &["ml"], Some(MILLILITER)),
+    ("Liter", "Liter", &["l"], Some(LITER)),
+    ("Teelöffel", "Teelöffel", &["TL"], Some(TEASPOON)),
+    ("Esslöffel", "Esslöffel", &["EL"], Some(TABLESPOON)),
+    ("Stück", "Stück", &["Stk."], Some(PIECE)),
+    ("Dose", "Dosen", &[], None),
+    ("Scheibe", "Scheiben", &[], None),
+    ("Prise", "Prisen", &["Pr."], None),
+    ("Packung", "Packungen", &["Pck.", "Pkg.", "Pkt."], None),
+    ("Stiel", "Stiele", &[], None),
+    ("Bund", "Bunde", &[], None),
+    ("Messerspitze", "Messerspitzen", &["Msp."], None),
+    ("Glas", "Gläser", &[], None),
+    ("Zehe", "Zehen", &[], None),
+    ("Becher", "Becher", &[], None),
+    ("Tasse", "Tassen", &[], None),
+    ("Flasche", "Flaschen", &[], None),
+    ("Beutel", "Beutel", &[], None),
+    ("Würfel", "Würfel", &[], None),
+    ("Stange", "Stangen", &[], None),
+    ("Handvoll", "Handvoll", &[], None),
+    ("Zentimeter", "Zentimeter", &["cm"], None),
+];
+
+/// Spellings of a unit in ingredient lines.
+#[derive(Debug, Clone, PartialEq)]
+struct UnitName {
+    singular: String,
+    plural: String,
+    abbreviations: Vec<String>,
+    unit: Option<Unit>,
+}
+
+impl UnitName {
+    /// Whether a word is one of the spellings.
+    fn matches(&self, word: &str) -> bool {
+        spelled(word, &self.singular, &self.plural, &self.abbreviations)
+    }
+
+    /// Spelling used in ingredient lists: the symbol of a measure, otherwise
+    /// the singular.
+    fn canonical(&self) -> &str {
+        self.unit.map_or(&self.singular, |unit| unit.symbol)
+    }
+}
+
+/// Whether a word is one of the spellings, ignoring case and a trailing period.
+fn spelled(word: &str, singular: &str, plural: &str, abbreviations: &[impl AsRef<str>]) -> bool {
+    let word = fold(word);
+    [singular, plural]
+        .into_iter()
+        .chain(abbreviations.iter().map(AsRef::as_ref))
+        .any(|form| fold(form) == word)
+}
+
+fn fold(word: &str) -> String {
+    word.trim().trim_end_matches('.').to_lowercase()
+}
+
+/// Units recognized between the quantity and the name of an ingredient.
+///
+/// Built in units can be extended with a markdown table of singular, plural
+/// and abbreviations:
+///
+/// ```text
+/// | Einheit | Mehrzahl | Abkürzungen |
+/// | Packung | Packungen | Pck., Pkg. |
+/// | Zehe    | Zehen     |            |
+/// ```
+#[derive(Debug)]
+pub(crate) struct UnitRegistry {
+    names: Vec<UnitName>,
+}
+
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        let names = UNIT_NAMES
+            .iter()
+            .map(|(singular, plural, abbreviations, unit)| UnitName {
+                singular: singular.to_string(),
+                plural: plural.to_string(),
+                abbreviations: abbreviations.iter().map(|a| a.to_string()).collect(),
+                unit: *unit,
+            })
+            .collect();
+        Self { names }
+    }
+}
+
+impl UnitRegistry {
+    /// Load additional units, a missing file yields the built in units.
+    pub(crate) fn from_file(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read unit file: {}", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse table rows on top of the built in units, the header is skipped.
+    pub(crate) fn parse(content: &str) -> Self {
+        let mut registry = Self::default();
+
+        for line in content.lines().map(str::trim) {
+            if !line.starts_with('|') {
+                continue;
+            }
+            let cells: Vec<&str> = line.trim_matches('|').split('|').map(str::trim).collect();
+            let (singular, plural, abbreviations) = match cells[..] {
+                [singular] => (singular, "", ""),
+                [singular, plural] => (singular, plural, ""),
+                [singular, plural, abbreviations] => (singular, plural, abbreviations),
+                _ => continue,
+            };
+            if singular.is_empty()
+                || singular.eq_ignore_ascii_case("Einheit")
+                || singular.chars().all(|c| matches!(c, '-' | ':'))
+            {
+                continue;
+            }
+            registry.names.push(UnitName {
+                singular: singular.to_string(),
+                plural: if plural.is_empty() { singular } else { plural }.to_string(),
+                abbreviations: abbreviations
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+                unit: None,
+            });
+        }
+
+        registry
+    }
+
+    /// Whether a word is a known unit.
+    pub(crate) fn is_unit(&self, word: &str) -> bool {
+        self.names.iter().any(|name| name.matches(word))
+    }
+
+    /// Split the text after a quantity into its unit and the name.
+    ///
+    /// The unit is returned in its canonical spelling, so "Zehen" and "Zehe"
+    /// or "EL" and "Esslöffel" end up on the same shopping list line. Only a
+    /// whole first word counts as unit, so "Lorbeerblatt" keeps its "L". The
+    /// unit is empty if the first word is not known or nothing follows it.
+    pub(crate) fn split<'s, 'a>(&'s self, text: &'a str) -> (&'s str, &'a str) {
+        let text = text.trim();
+        let Some((word, name)) = text.split_once(char::is_whitespace) else {
+            return ("", text);
+        };
+        match self.names.iter().find(|unit| unit.matches(word)) {
+            Some(unit) => (unit.canonical(), name.trim_start()),
+            None => ("", text),
+        }
+    }
+
+    /// First word of an ingredient name that looks like a unit, e.g. "Pck." in
+    /// "Pck. Vanillezucker" or "Prise" in an ingredient without quantity.
+    pub(crate) fn probable_unit<'a>(&self, name: &'a str) -> Option<&'a str> {
+        let (word, _) = name.trim().split_once(char::is_whitespace)?;
+        let abbreviated = word.strip_suffix('.').is_some_and(|stem| {
+            (1..=4).contains(&stem.len()) && stem.chars().all(char::is_alphabetic)
+        });
+        (abbreviated || self.is_unit(word)).then_some(word)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_known_spellings() {
+        assert_eq!(Unit::parse("G"), Some(GRAM));
+        assert_eq!(Unit::parse("KG"), Some(KILOGRAM));
+        assert_eq!(Unit::parse("Liter"), Some(LITER));
+        assert_eq!(Unit::parse("el"), Some(TABLESPOON));
+        assert_eq!(Unit::parse("Stk."), Some(PIECE));
+        assert_eq!(Unit::parse("Stück"), Some(PIECE));
+        assert_eq!(Unit::parse("Esslöffel"), Some(TABLESPOON));
+        assert_eq!(Unit::parse("Kilogramm"), Some(KILOGRAM));
+        assert_eq!(Unit::parse("Prise"), None);
+    }
+
+    #[test]
+    fn test_readable_picks_larger_unit() {
+        assert_eq!(Family::Mass.readable(2400.0), (2.4, "kg"));
+        assert_eq!(Family::Mass.readable(250.0), (250.0, "g"));
+        let (amount, symbol) = Family::Mass.readable(0.5);
+        assert!((amount - 500.0).abs() < 1e-3);
+        assert_eq!(symbol, "mg");
+        assert_eq!(Family::Volume.readable(1500.0), (1.5, "l"));
+        assert_eq!(Family::Spoon.readable(6.0), (2.0, "EL"));
+        assert_eq!(Family::Spoon.readable(2.0), (2.0, "TL"));
+        assert_eq!(Family::Count.readable(7.0), (7.0, "Stück"));
+    }
+
+    #[test]
+    fn test_convert_between_measures() {
+        assert_eq!(convert(1.5, "kg", "g"), Some(1500.0));
+        assert_eq!(convert(2.0, "EL", "TL"), Some(6.0));
+        assert_eq!(convert(3.0, "", "Stück"), Some(3.0));
+        assert_eq!(convert(2.0, "Dose", "dose"), Some(2.0));
+        assert_eq!(convert(1.0, "EL", "g"), None);
+        assert_eq!(convert(1.0, "Prise", "g"), None);
+    }
+
+    #[test]
+    fn test_registry_splits_whole_words() {
+        let registry = UnitRegistry::parse(
+            "| Einheit | Mehrzahl | Abkürzungen |\n\
+             | ------- | -------- | ----------- |\n\
+             | Knolle  | Knollen  | Kn.         |\n",
+        );
+
+        assert_eq!(registry.split("g Butter"), ("g", "Butter"));
+        assert_eq!(registry.split("Gramm Butter"), ("g", "Butter"));
+        assert_eq!(
+            registry.split("Pck. Vanillezucker"),
+            ("Packung", "Vanillezucker")
+        );
+        assert_eq!(registry.split("Zehen Knoblauch"), ("Zehe", "Knoblauch"));
+        assert_eq!(registry.split("Kn. Sellerie"), ("Knolle", "Sellerie"));
+        assert_eq!(registry.split("Lorbeerblatt"), ("", "Lorbeerblatt"));
+        assert_eq!(
+            registry.split("gemahlene Mandeln"),
+            ("", "gemahlene Mandeln")
+        );
+        assert_eq!(registry.split("Becher"), ("", "Becher"));
+
+        assert_eq!(registry.probable_unit("Prise Salz"), Some("Prise"));
+        assert_eq!(registry.probable_unit("Pfd. Kartoffeln"), Some("Pfd."));
+        assert_eq!(registry.probable_unit("Knollen Sellerie"), Some("Knollen"));
+        assert_eq!(registry.probable_unit("rote Zwiebeln"), None);
+        assert_eq!(registry.probable_unit("Salz"), None);
+    }
+}
@@ -16,7 +16,7 @@ module.exports = grammar({
       seq(
         field("count", $.integer),
         /[\t ]+/,
-        choice("Personen", "Portionen")
+        choice("Personen", "Portionen", field("unit", $.batch_unit))
       ),
 
     ingredients_section: $ => seq("## Zutaten", repeat1($.ingredient_line)),
@@ -32,12 +32,15 @@ module.exports = grammar({
 
 
     // Tokens
-    quantity: $ => choice($.float, $.integer),
+    quantity: $ => choice($.float, $.integer, $.fraction, seq($.integer, $.fraction), $.range),
     integer: _ => token(prec(2,/\d+/)),
     float: _ => token(prec(2,/\d+[\.,]\d+/)),
+    fraction: _ => token(prec(2,/\d+\/\d+|\d*[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]/)),
+    range: _ => token(prec(2,/(\d+\/\d+|\d*[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]|\d+([\.,]\d+)?)[-–](\d+\/\d+|\d*[½⅓⅔¼¾⅕⅖⅗⅘⅙⅚⅛⅜⅝⅞]|\d+([\.,]\d+)?)/)),
     unit: _ => token(prec(3, choice("Dosen", "Dose", "g", "G", "mg", "MG", "kg", "KG", "el", "EL", "tl", "TL", "l", "L", "ml","ML", "Liter", "stk", "Stk", "Scheiben", "scheiben", "scheibe", "Pr.", "Stück", "Packung", "Packungen", "Pkg.", "Prise", "Stiele", "Bund", "Messerspitze", "Msp", "Glas", "glas"))),
+    batch_unit: _ => /[^\s\d#\-]([^\n\r]*[^\s])?/,
     text: _ => /[^\n\r]+/,
-    ingredient_name: _ => /[^\n\r-]+/,
+    ingredient_name: _ => /[^\s\-–][^\n\r-]*/,
     preamble_line: _ => /[^#\-\n\r][^\n\r]*/,
   }
 });
@@ -7,16 +7,16 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 27
+#define STATE_COUNT 30
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 22
+#define SYMBOL_COUNT 25
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 13
+#define TOKEN_COUNT 16
 #define EXTERNAL_TOKEN_COUNT 0
 #define FIELD_COUNT 4
 #define MAX_ALIAS_SEQUENCE_LENGTH 4
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 5
+#define PRODUCTION_ID_COUNT 6
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
@@ -28,19 +28,22 @@ enum ts_symbol_identifiers {
   anon_sym_DASH = 6,
   sym_integer = 7,
   sym_float = 8,
-  sym_unit = 9,
-  sym_text = 10,
-  sym_ingredient_name = 11,
-  sym_preamble_line = 12,
-  sym_source_file = 13,
-  sym_persons_line = 14,
-  sym_ingredients_section = 15,
-  sym_preparation_section = 16,
-  sym_ingredient_line = 17,
-  sym_quantity = 18,
-  aux_sym_source_file_repeat1 = 19,
-  aux_sym_ingredients_section_repeat1 = 20,
-  aux_sym_preparation_section_repeat1 = 21,
+  sym_fraction = 9,
+  sym_range = 10,
+  sym_unit = 11,
+  sym_batch_unit = 12,
+  sym_text = 13,
+  sym_ingredient_name = 14,
+  sym_preamble_line = 15,
+  sym_source_file = 16,
+  sym_persons_line = 17,
+  sym_ingredients_section = 18,
+  sym_preparation_section = 19,
+  sym_ingredient_line = 20,
+  sym_quantity = 21,
+  aux_sym_source_file_repeat1 = 22,
+  aux_sym_ingredients_section_repeat1 = 23,
+  aux_sym_preparation_section_repeat1 = 24,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -53,7 +56,10 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_DASH] = "-",
   [sym_integer] = "integer",
   [sym_float] = "float",
+  [sym_fraction] = "fraction",
+  [sym_range] = "range",
   [sym_unit] = "unit",
+  [sym_batch_unit] = "batch_unit",
   [sym_text] = "text",
   [sym_ingredient_name] = "ingredient_name",
   [sym_preamble_line] = "preamble_line",
@@ -78,7 +84,10 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_DASH] = anon_sym_DASH,
   [sym_integer] = sym_integer,
   [sym_float] = sym_float,
+  [sym_fraction] = sym_fraction,
+  [sym_range] = sym_range,
   [sym_unit] = sym_unit,
+  [sym_batch_unit] = sym_batch_unit,
   [sym_text] = sym_text,
   [sym_ingredient_name] = sym_ingredient_name,
   [sym_preamble_line] = sym_preamble_line,
@@ -130,10 +139,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_fraction] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_range] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_unit] = {
     .visible = true,
     .named = true,
   },
+  [sym_batch_unit] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_text] = {
     .visible = true,
     .named = true,
@@ -201,20 +222,24 @@ static const char * const ts_field_names[] = {
 
 static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
-  [2] = {.index = 1, .length = 1},
-  [3] = {.index = 2, .length = 2},
-  [4] = {.index = 4, .length = 3},
+  [2] = {.index = 1, .length = 2},
+  [3] = {.index = 3, .length = 1},
+  [4] = {.index = 4, .length = 2},
+  [5] = {.index = 6, .length = 3},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
   [0] =
     {field_count, 0},
   [1] =
+    {field_count, 0},
+    {field_unit, 2},
+  [3] =
     {field_name, 1},
-  [2] =
+  [4] =
     {field_name, 2},
     {field_quantity, 1},
-  [4] =
+  [6] =
     {field_name, 3},
     {field_quantity, 1},
     {field_unit, 2},
@@ -256,6 +281,9 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [24] = 24,
   [25] = 25,
   [26] = 26,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -263,1241 +291,1552 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(106);
+      if (eof) ADVANCE(111);
       ADVANCE_MAP(
-        '#', 10,
-        '-', 114,
-        'B', 94,
-        'D', 71,
-        'E', 16,
-        'G', 125,
+        '#', 11,
+        '-', 121,
+        'B', 12,
+        'D', 13,
+        'E', 14,
+        'G', 138,
         'K', 15,
-        'L', 123,
-        'M', 35,
-        'P', 20,
-        'S', 27,
-        'T', 16,
-        'e', 60,
-        'g', 125,
-        'k', 46,
-        'l', 120,
-        'm', 101,
-        's', 30,
-        't', 60,
+        'L', 139,
+        'M', 16,
+        'P', 17,
+        'S', 18,
+        'T', 14,
+        'e', 19,
+        'g', 138,
+        'k', 20,
+        'l', 140,
+        'm', 21,
+        's', 22,
+        't', 19,
       );
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(116);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(123);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(128);
       END_STATE();
     case 1:
-      if (lookahead == '\n') SKIP(1);
-      if (lookahead == '#') ADVANCE(12);
+      if (lookahead == 'P') ADVANCE(147);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(196);
+          lookahead == '\n' ||
+          lookahead == ' ') SKIP(1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(124);
       if (lookahead != 0 &&
-          lookahead != '\r' &&
-          lookahead != '-') ADVANCE(215);
+          (lookahead < '\v' || '\r' < lookahead) &&
+          lookahead != '#' &&
+          lookahead != '-') ADVANCE(146);
       END_STATE();
     case 2:
-      if (lookahead == '\n') SKIP(2);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(185);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(117);
+          lookahead == '\n' ||
+          lookahead == ' ') SKIP(2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(129);
       if (lookahead != 0 &&
-          lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          (lookahead < '\v' || '\r' < lookahead) &&
+          lookahead != '-' &&
+          lookahead != 0x2013) ADVANCE(163);
       END_STATE();
     case 3:
+      if (eof) ADVANCE(111);
       if (lookahead == '\n') SKIP(3);
-      if (lookahead == '-') ADVANCE(114);
+      if (lookahead == '#') ADVANCE(23);
+      if (lookahead == '-') ADVANCE(121);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(192);
+          lookahead == ' ') ADVANCE(226);
       if (lookahead != 0 &&
-          lookahead != '\r' &&
-          lookahead != '#') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
     case 4:
       if (lookahead == '\n') SKIP(4);
-      if (lookahead == 'B') ADVANCE(182);
-      if (lookahead == 'D') ADVANCE(169);
-      if (lookahead == 'E') ADVANCE(134);
-      if (lookahead == 'G') ADVANCE(126);
-      if (lookahead == 'K') ADVANCE(133);
-      if (lookahead == 'L') ADVANCE(124);
-      if (lookahead == 'M') ADVANCE(149);
-      if (lookahead == 'P') ADVANCE(135);
-      if (lookahead == 'S') ADVANCE(139);
-      if (lookahead == 'T') ADVANCE(134);
-      if (lookahead == 'e') ADVANCE(164);
-      if (lookahead == 'g') ADVANCE(126);
-      if (lookahead == 'k') ADVANCE(153);
-      if (lookahead == 'l') ADVANCE(120);
-      if (lookahead == 'm') ADVANCE(187);
-      if (lookahead == 's') ADVANCE(142);
-      if (lookahead == 't') ADVANCE(164);
+      if (lookahead == '#') ADVANCE(24);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(132);
+          lookahead == ' ') ADVANCE(227);
       if (lookahead != 0 &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(225);
       END_STATE();
     case 5:
       if (lookahead == '\n') SKIP(5);
+      if (lookahead == '-') ADVANCE(121);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(107);
+          lookahead == ' ') ADVANCE(228);
+      if (lookahead != 0 &&
+          lookahead != '\r' &&
+          lookahead != '#') ADVANCE(225);
       END_STATE();
     case 6:
+      if (eof) ADVANCE(111);
       if (lookahead == '\n') SKIP(6);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(186);
+          lookahead == ' ') ADVANCE(162);
       if (lookahead != 0 &&
-          lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '\r') ADVANCE(161);
       END_STATE();
     case 7:
-      if (lookahead == ' ') ADVANCE(17);
+      ADVANCE_MAP(
+        'B', 165,
+        'D', 166,
+        'E', 167,
+        'G', 141,
+        'K', 168,
+        'L', 142,
+        'M', 169,
+        'P', 170,
+        'S', 171,
+        'T', 167,
+        'e', 172,
+        'g', 141,
+        'k', 173,
+        'l', 140,
+        'm', 174,
+        's', 175,
+        't', 172,
+      );
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == ' ') SKIP(7);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(164);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(130);
+      if (lookahead != 0 &&
+          (lookahead < '\v' || '\r' < lookahead) &&
+          lookahead != '-' &&
+          lookahead != 0x2013) ADVANCE(163);
       END_STATE();
     case 8:
-      if (lookahead == ' ') ADVANCE(18);
+      ADVANCE_MAP(
+        'B', 165,
+        'D', 166,
+        'E', 167,
+        'G', 141,
+        'K', 168,
+        'L', 142,
+        'M', 169,
+        'P', 170,
+        'S', 171,
+        'T', 167,
+        'e', 172,
+        'g', 141,
+        'k', 173,
+        'l', 140,
+        'm', 174,
+        's', 175,
+        't', 172,
+      );
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == ' ') SKIP(8);
+      if (lookahead != 0 &&
+          (lookahead < '\v' || '\r' < lookahead) &&
+          lookahead != '-' &&
+          lookahead != 0x2013) ADVANCE(163);
       END_STATE();
     case 9:
-      if (lookahead == ' ') ADVANCE(19);
+      if (lookahead == '\n') SKIP(9);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(112);
       END_STATE();
     case 10:
-      if (lookahead == '#') ADVANCE(7);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == ' ') SKIP(10);
+      if (lookahead != 0 &&
+          (lookahead < '\v' || '\r' < lookahead) &&
+          lookahead != '-' &&
+          lookahead != 0x2013) ADVANCE(163);
       END_STATE();
     case 11:
-      if (lookahead == '#') ADVANCE(8);
+      if (lookahead == '#') ADVANCE(25);
       END_STATE();
     case 12:
-      if (lookahead == '#') ADVANCE(9);
+      if (lookahead == 'u') ADVANCE(29);
       END_STATE();
     case 13:
-      if (lookahead == '.') ADVANCE(120);
+      if (lookahead == 'o') ADVANCE(30);
       END_STATE();
     case 14:
-      if (lookahead == '.') ADVANCE(120);
-      if (lookahead == 'i') ADVANCE(86);
+      if (lookahead == 'L') ADVANCE(140);
       END_STATE();
     case 15:
-      if (lookahead == 'G') ADVANCE(120);
+      if (lookahead == 'G') ADVANCE(140);
       END_STATE();
     case 16:
-      if (lookahead == 'L') ADVANCE(120);
+      if (lookahead == 'G') ADVANCE(140);
+      if (lookahead == 'L') ADVANCE(140);
+      if (lookahead == 'e') ADVANCE(33);
+      if (lookahead == 's') ADVANCE(34);
       END_STATE();
     case 17:
-      if (lookahead == 'Z') ADVANCE(95);
+      if (lookahead == 'a') ADVANCE(35);
+      if (lookahead == 'e') ADVANCE(36);
+      if (lookahead == 'k') ADVANCE(37);
+      if (lookahead == 'o') ADVANCE(38);
+      if (lookahead == 'r') ADVANCE(39);
       END_STATE();
     case 18:
-      if (lookahead == 'Z') ADVANCE(98);
+      if (lookahead == 'c') ADVANCE(40);
+      if (lookahead == 't') ADVANCE(41);
       END_STATE();
     case 19:
-      if (lookahead == 'Z') ADVANCE(97);
+      if (lookahead == 'l') ADVANCE(140);
       END_STATE();
     case 20:
-      if (lookahead == 'a') ADVANCE(29);
-      if (lookahead == 'e') ADVANCE(78);
-      if (lookahead == 'k') ADVANCE(47);
-      if (lookahead == 'o') ADVANCE(77);
-      if (lookahead == 'r') ADVANCE(14);
+      if (lookahead == 'g') ADVANCE(140);
       END_STATE();
     case 21:
-      if (lookahead == 'a') ADVANCE(81);
+      if (lookahead == 'g') ADVANCE(140);
+      if (lookahead == 'l') ADVANCE(140);
       END_STATE();
     case 22:
-      if (lookahead == 'a') ADVANCE(92);
+      if (lookahead == 'c') ADVANCE(42);
+      if (lookahead == 't') ADVANCE(43);
       END_STATE();
     case 23:
-      if (lookahead == 'b') ADVANCE(33);
+      if (lookahead == '#') ADVANCE(45);
       END_STATE();
     case 24:
-      if (lookahead == 'b') ADVANCE(41);
+      if (lookahead == '#') ADVANCE(46);
       END_STATE();
     case 25:
-      if (lookahead == 'b') ADVANCE(40);
+      if (lookahead == ' ') ADVANCE(47);
       END_STATE();
     case 26:
-      if (lookahead == 'b') ADVANCE(40);
-      if (lookahead == 't') ADVANCE(22);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
       END_STATE();
     case 27:
-      if (lookahead == 'c') ADVANCE(50);
-      if (lookahead == 't') ADVANCE(56);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(134);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(135);
       END_STATE();
     case 28:
-      if (lookahead == 'c') ADVANCE(58);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(131);
       END_STATE();
     case 29:
-      if (lookahead == 'c') ADVANCE(59);
+      if (lookahead == 'n') ADVANCE(48);
       END_STATE();
     case 30:
-      if (lookahead == 'c') ADVANCE(51);
-      if (lookahead == 't') ADVANCE(58);
+      if (lookahead == 's') ADVANCE(49);
       END_STATE();
     case 31:
-      if (lookahead == 'd') ADVANCE(120);
+      if (lookahead == 'a') ADVANCE(50);
       END_STATE();
     case 32:
-      if (lookahead == 'e') ADVANCE(120);
+      if (lookahead == 't') ADVANCE(51);
       END_STATE();
     case 33:
-      if (lookahead == 'e') ADVANCE(127);
+      if (lookahead == 's') ADVANCE(52);
       END_STATE();
     case 34:
-      if (lookahead == 'e') ADVANCE(61);
+      if (lookahead == 'p') ADVANCE(140);
       END_STATE();
     case 35:
-      if (lookahead == 'e') ADVANCE(87);
-      if (lookahead == 's') ADVANCE(74);
-      if (lookahead == 'G' ||
-          lookahead == 'L') ADVANCE(120);
+      if (lookahead == 'c') ADVANCE(53);
       END_STATE();
     case 36:
-      if (lookahead == 'e') ADVANCE(52);
+      if (lookahead == 'r') ADVANCE(54);
       END_STATE();
     case 37:
-      if (lookahead == 'e') ADVANCE(76);
+      if (lookahead == 'g') ADVANCE(55);
       END_STATE();
     case 38:
-      if (lookahead == 'e') ADVANCE(53);
+      if (lookahead == 'r') ADVANCE(56);
       END_STATE();
     case 39:
-      if (lookahead == 'e') ADVANCE(64);
+      if (lookahead == '.') ADVANCE(140);
+      if (lookahead == 'i') ADVANCE(57);
       END_STATE();
     case 40:
-      if (lookahead == 'e') ADVANCE(80);
+      if (lookahead == 'h') ADVANCE(58);
       END_STATE();
     case 41:
-      if (lookahead == 'e') ADVANCE(62);
+      if (lookahead == 'i') ADVANCE(59);
+      if (lookahead == 'k') ADVANCE(140);
+      if (lookahead == 0xfc) ADVANCE(60);
       END_STATE();
     case 42:
-      if (lookahead == 'e') ADVANCE(55);
+      if (lookahead == 'h') ADVANCE(61);
       END_STATE();
     case 43:
-      if (lookahead == 'e') ADVANCE(65);
+      if (lookahead == 'k') ADVANCE(140);
       END_STATE();
     case 44:
-      if (lookahead == 'e') ADVANCE(66);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
       END_STATE();
     case 45:
-      if (lookahead == 'e') ADVANCE(79);
+      if (lookahead == ' ') ADVANCE(62);
       END_STATE();
     case 46:
-      if (lookahead == 'g') ADVANCE(120);
+      if (lookahead == ' ') ADVANCE(63);
       END_STATE();
     case 47:
-      if (lookahead == 'g') ADVANCE(13);
+      if (lookahead == 'Z') ADVANCE(64);
       END_STATE();
     case 48:
-      if (lookahead == 'g') ADVANCE(122);
+      if (lookahead == 'd') ADVANCE(140);
       END_STATE();
     case 49:
-      if (lookahead == 'g') ADVANCE(112);
+      if (lookahead == 'e') ADVANCE(143);
       END_STATE();
     case 50:
-      if (lookahead == 'h') ADVANCE(36);
+      if (lookahead == 's') ADVANCE(140);
       END_STATE();
     case 51:
-      if (lookahead == 'h') ADVANCE(38);
+      if (lookahead == 'e') ADVANCE(66);
       END_STATE();
     case 52:
-      if (lookahead == 'i') ADVANCE(24);
+      if (lookahead == 's') ADVANCE(67);
       END_STATE();
     case 53:
-      if (lookahead == 'i') ADVANCE(23);
+      if (lookahead == 'k') ADVANCE(68);
       END_STATE();
     case 54:
-      if (lookahead == 'i') ADVANCE(89);
+      if (lookahead == 's') ADVANCE(69);
       END_STATE();
     case 55:
-      if (lookahead == 'i') ADVANCE(93);
+      if (lookahead == '.') ADVANCE(140);
       END_STATE();
     case 56:
-      if (lookahead == 'i') ADVANCE(34);
-      if (lookahead == 'k') ADVANCE(120);
-      if (lookahead == 0xfc) ADVANCE(28);
+      if (lookahead == 't') ADVANCE(70);
       END_STATE();
     case 57:
-      if (lookahead == 'i') ADVANCE(73);
+      if (lookahead == 's') ADVANCE(71);
       END_STATE();
     case 58:
-      if (lookahead == 'k') ADVANCE(120);
+      if (lookahead == 'e') ADVANCE(72);
       END_STATE();
     case 59:
-      if (lookahead == 'k') ADVANCE(96);
+      if (lookahead == 'e') ADVANCE(73);
       END_STATE();
     case 60:
-      if (lookahead == 'l') ADVANCE(120);
+      if (lookahead == 'c') ADVANCE(43);
       END_STATE();
     case 61:
-      if (lookahead == 'l') ADVANCE(32);
+      if (lookahead == 'e') ADVANCE(74);
       END_STATE();
     case 62:
-      if (lookahead == 'n') ADVANCE(120);
+      if (lookahead == 'Z') ADVANCE(75);
       END_STATE();
     case 63:
-      if (lookahead == 'n') ADVANCE(31);
+      if (lookahead == 'Z') ADVANCE(76);
       END_STATE();
     case 64:
-      if (lookahead == 'n') ADVANCE(108);
+      if (lookahead == 'u') ADVANCE(77);
       END_STATE();
     case 65:
-      if (lookahead == 'n') ADVANCE(109);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
       END_STATE();
     case 66:
-      if (lookahead == 'n') ADVANCE(110);
+      if (lookahead == 'r') ADVANCE(140);
       END_STATE();
     case 67:
-      if (lookahead == 'n') ADVANCE(48);
+      if (lookahead == 'e') ADVANCE(78);
       END_STATE();
     case 68:
-      if (lookahead == 'n') ADVANCE(49);
+      if (lookahead == 'u') ADVANCE(79);
       END_STATE();
     case 69:
-      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 'o') ADVANCE(80);
       END_STATE();
     case 70:
-      if (lookahead == 'n') ADVANCE(43);
+      if (lookahead == 'i') ADVANCE(81);
       END_STATE();
     case 71:
-      if (lookahead == 'o') ADVANCE(82);
+      if (lookahead == 'e') ADVANCE(140);
       END_STATE();
     case 72:
-      if (lookahead == 'o') ADVANCE(69);
+      if (lookahead == 'i') ADVANCE(82);
       END_STATE();
     case 73:
-      if (lookahead == 'o') ADVANCE(70);
+      if (lookahead == 'l') ADVANCE(71);
       END_STATE();
     case 74:
-      if (lookahead == 'p') ADVANCE(120);
+      if (lookahead == 'i') ADVANCE(83);
       END_STATE();
     case 75:
-      if (lookahead == 'p') ADVANCE(54);
+      if (lookahead == 'u') ADVANCE(84);
       END_STATE();
     case 76:
-      if (lookahead == 'r') ADVANCE(120);
+      if (lookahead == 'u') ADVANCE(85);
       END_STATE();
     case 77:
-      if (lookahead == 'r') ADVANCE(88);
+      if (lookahead == 'b') ADVANCE(86);
+      if (lookahead == 't') ADVANCE(87);
       END_STATE();
     case 78:
-      if (lookahead == 'r') ADVANCE(83);
+      if (lookahead == 'r') ADVANCE(88);
       END_STATE();
     case 79:
-      if (lookahead == 'r') ADVANCE(84);
+      if (lookahead == 'n') ADVANCE(89);
       END_STATE();
     case 80:
-      if (lookahead == 'r') ADVANCE(42);
+      if (lookahead == 'n') ADVANCE(90);
       END_STATE();
     case 81:
-      if (lookahead == 's') ADVANCE(120);
+      if (lookahead == 'o') ADVANCE(91);
       END_STATE();
     case 82:
-      if (lookahead == 's') ADVANCE(33);
+      if (lookahead == 'b') ADVANCE(92);
       END_STATE();
     case 83:
-      if (lookahead == 's') ADVANCE(72);
+      if (lookahead == 'b') ADVANCE(49);
       END_STATE();
     case 84:
-      if (lookahead == 's') ADVANCE(75);
+      if (lookahead == 'b') ADVANCE(86);
       END_STATE();
     case 85:
-      if (lookahead == 's') ADVANCE(45);
+      if (lookahead == 't') ADVANCE(87);
       END_STATE();
     case 86:
-      if (lookahead == 's') ADVANCE(32);
+      if (lookahead == 'e') ADVANCE(93);
       END_STATE();
     case 87:
-      if (lookahead == 's') ADVANCE(85);
+      if (lookahead == 'a') ADVANCE(94);
       END_STATE();
     case 88:
-      if (lookahead == 't') ADVANCE(57);
+      if (lookahead == 's') ADVANCE(95);
       END_STATE();
     case 89:
-      if (lookahead == 't') ADVANCE(100);
+      if (lookahead == 'g') ADVANCE(144);
       END_STATE();
     case 90:
-      if (lookahead == 't') ADVANCE(22);
+      if (lookahead == 'e') ADVANCE(96);
       END_STATE();
     case 91:
-      if (lookahead == 't') ADVANCE(37);
+      if (lookahead == 'n') ADVANCE(97);
       END_STATE();
     case 92:
-      if (lookahead == 't') ADVANCE(44);
+      if (lookahead == 'e') ADVANCE(98);
       END_STATE();
     case 93:
-      if (lookahead == 't') ADVANCE(99);
+      if (lookahead == 'r') ADVANCE(99);
       END_STATE();
     case 94:
-      if (lookahead == 'u') ADVANCE(63);
+      if (lookahead == 't') ADVANCE(100);
       END_STATE();
     case 95:
-      if (lookahead == 'u') ADVANCE(26);
+      if (lookahead == 'p') ADVANCE(101);
       END_STATE();
     case 96:
-      if (lookahead == 'u') ADVANCE(67);
+      if (lookahead == 'n') ADVANCE(113);
       END_STATE();
     case 97:
-      if (lookahead == 'u') ADVANCE(90);
+      if (lookahead == 'e') ADVANCE(102);
       END_STATE();
     case 98:
-      if (lookahead == 'u') ADVANCE(25);
+      if (lookahead == 'n') ADVANCE(140);
       END_STATE();
     case 99:
-      if (lookahead == 'u') ADVANCE(68);
+      if (lookahead == 'e') ADVANCE(103);
       END_STATE();
     case 100:
-      if (lookahead == 'z') ADVANCE(32);
+      if (lookahead == 'e') ADVANCE(104);
       END_STATE();
     case 101:
-      if (lookahead == 'g' ||
-          lookahead == 'l') ADVANCE(120);
+      if (lookahead == 'i') ADVANCE(105);
       END_STATE();
     case 102:
-      if (lookahead == '\t' ||
-          lookahead == '\n' ||
-          lookahead == ' ') SKIP(102);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      if (lookahead == 'n') ADVANCE(115);
       END_STATE();
     case 103:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      if (lookahead == 'i') ADVANCE(106);
       END_STATE();
     case 104:
-      if (eof) ADVANCE(106);
-      if (lookahead == '\n') SKIP(104);
-      if (lookahead == '#') ADVANCE(11);
-      if (lookahead == '-') ADVANCE(114);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(193);
-      if (lookahead != 0 &&
-          lookahead != '\r') ADVANCE(215);
+      if (lookahead == 'n') ADVANCE(117);
       END_STATE();
     case 105:
-      if (eof) ADVANCE(106);
-      if (lookahead == '\n') SKIP(105);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(128);
-      if (lookahead != 0 &&
-          lookahead != '\r') ADVANCE(129);
+      if (lookahead == 't') ADVANCE(107);
       END_STATE();
     case 106:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == 't') ADVANCE(108);
       END_STATE();
     case 107:
+      if (lookahead == 'z') ADVANCE(71);
+      END_STATE();
+    case 108:
+      if (lookahead == 'u') ADVANCE(109);
+      END_STATE();
+    case 109:
+      if (lookahead == 'n') ADVANCE(110);
+      END_STATE();
+    case 110:
+      if (lookahead == 'g') ADVANCE(119);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(ts_builtin_sym_end);
+      END_STATE();
+    case 112:
       ACCEPT_TOKEN(aux_sym_persons_line_token1);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(107);
+          lookahead == ' ') ADVANCE(112);
       END_STATE();
-    case 108:
+    case 113:
       ACCEPT_TOKEN(anon_sym_Personen);
       END_STATE();
-    case 109:
+    case 114:
+      ACCEPT_TOKEN(anon_sym_Personen);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 115:
       ACCEPT_TOKEN(anon_sym_Portionen);
       END_STATE();
-    case 110:
+    case 116:
+      ACCEPT_TOKEN(anon_sym_Portionen);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 117:
       ACCEPT_TOKEN(anon_sym_POUND_POUNDZutaten);
       END_STATE();
-    case 111:
+    case 118:
       ACCEPT_TOKEN(anon_sym_POUND_POUNDZutaten);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 112:
+    case 119:
       ACCEPT_TOKEN(anon_sym_POUND_POUNDZubereitung);
       END_STATE();
-    case 113:
+    case 120:
       ACCEPT_TOKEN(anon_sym_POUND_POUNDZubereitung);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 114:
+    case 121:
       ACCEPT_TOKEN(anon_sym_DASH);
       END_STATE();
-    case 115:
+    case 122:
       ACCEPT_TOKEN(anon_sym_DASH);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 116:
+    case 123:
       ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '/') ADVANCE(28);
       if (lookahead == ',' ||
-          lookahead == '.') ADVANCE(103);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(116);
+          lookahead == '.') ADVANCE(26);
+      if (lookahead == '-' ||
+          lookahead == 0x2013) ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(123);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(128);
       END_STATE();
-    case 117:
+    case 124:
       ACCEPT_TOKEN(sym_integer);
-      if (lookahead == ',' ||
-          lookahead == '.') ADVANCE(188);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(117);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(124);
       END_STATE();
-    case 118:
+    case 125:
       ACCEPT_TOKEN(sym_integer);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(118);
+      if (lookahead == '-') ADVANCE(27);
+      if (lookahead == '/') ADVANCE(177);
+      if (lookahead == 0x2013) ADVANCE(178);
+      if (lookahead == ',' ||
+          lookahead == '.') ADVANCE(176);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(125);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(129);
       END_STATE();
-    case 119:
+    case 126:
       ACCEPT_TOKEN(sym_float);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      if (lookahead == '-' ||
+          lookahead == 0x2013) ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(126);
       END_STATE();
-    case 120:
+    case 127:
+      ACCEPT_TOKEN(sym_float);
+      if (lookahead == '-') ADVANCE(27);
+      if (lookahead == 0x2013) ADVANCE(178);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(sym_fraction);
+      if (lookahead == '-' ||
+          lookahead == 0x2013) ADVANCE(27);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_fraction);
+      if (lookahead == '-') ADVANCE(27);
+      if (lookahead == 0x2013) ADVANCE(178);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(sym_fraction);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(sym_fraction);
+      if (lookahead == '-' ||
+          lookahead == 0x2013) ADVANCE(27);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(131);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(sym_fraction);
+      if (lookahead == '-') ADVANCE(27);
+      if (lookahead == 0x2013) ADVANCE(178);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(132);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(sym_fraction);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(133);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(sym_range);
+      if (lookahead == '/') ADVANCE(65);
+      if (lookahead == ',' ||
+          lookahead == '.') ADVANCE(65);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(134);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(135);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(sym_range);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(sym_range);
+      if (lookahead == '/') ADVANCE(205);
+      if (lookahead == ',' ||
+          lookahead == '.') ADVANCE(205);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(136);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(135);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(sym_range);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
+      END_STATE();
+    case 138:
       ACCEPT_TOKEN(sym_unit);
+      if (lookahead == 'l') ADVANCE(31);
       END_STATE();
-    case 121:
+    case 139:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'e') ADVANCE(166);
+      if (lookahead == 'i') ADVANCE(32);
       END_STATE();
-    case 122:
+    case 140:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'e') ADVANCE(62);
       END_STATE();
-    case 123:
+    case 141:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'i') ADVANCE(91);
+      if (lookahead == 'l') ADVANCE(182);
       END_STATE();
-    case 124:
+    case 142:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'i') ADVANCE(181);
+      if (lookahead == 'i') ADVANCE(183);
       END_STATE();
-    case 125:
+    case 143:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'l') ADVANCE(21);
+      if (lookahead == 'n') ADVANCE(140);
       END_STATE();
-    case 126:
+    case 144:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'l') ADVANCE(136);
+      if (lookahead == 'e') ADVANCE(98);
       END_STATE();
-    case 127:
+    case 145:
       ACCEPT_TOKEN(sym_unit);
-      if (lookahead == 'n') ADVANCE(120);
+      if (lookahead == 'e') ADVANCE(221);
       END_STATE();
-    case 128:
-      ACCEPT_TOKEN(sym_text);
+    case 146:
+      ACCEPT_TOKEN(sym_batch_unit);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(128);
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(129);
+          lookahead != '\r') ADVANCE(146);
       END_STATE();
-    case 129:
+    case 147:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'e') ADVANCE(148);
+      if (lookahead == 'o') ADVANCE(149);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'r') ADVANCE(150);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'r') ADVANCE(151);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 's') ADVANCE(152);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 't') ADVANCE(153);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'o') ADVANCE(154);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'i') ADVANCE(155);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'n') ADVANCE(156);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'o') ADVANCE(157);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'e') ADVANCE(158);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'n') ADVANCE(159);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'n') ADVANCE(114);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'e') ADVANCE(160);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(sym_batch_unit);
+      if (lookahead == 'n') ADVANCE(116);
+      if (lookahead == '\t' ||
+          lookahead == '\v' ||
+          lookahead == '\f' ||
+          lookahead == ' ') ADVANCE(44);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(146);
+      END_STATE();
+    case 161:
       ACCEPT_TOKEN(sym_text);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(129);
+          lookahead != '\r') ADVANCE(161);
       END_STATE();
-    case 130:
+    case 162:
+      ACCEPT_TOKEN(sym_text);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(162);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r') ADVANCE(161);
+      END_STATE();
+    case 163:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == '.') ADVANCE(120);
-      if (lookahead == 'i') ADVANCE(178);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-' &&
-          lookahead != '.') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 131:
+    case 164:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == '.') ADVANCE(120);
+      if (lookahead == '/') ADVANCE(179);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(164);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(130);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-' &&
-          lookahead != '.') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 132:
+    case 165:
       ACCEPT_TOKEN(sym_ingredient_name);
-      ADVANCE_MAP(
-        'B', 182,
-        'D', 169,
-        'E', 134,
-        'G', 126,
-        'K', 133,
-        'L', 124,
-        'M', 149,
-        'P', 135,
-        'S', 139,
-        'T', 134,
-        'e', 164,
-        'g', 126,
-        'k', 153,
-        'l', 120,
-        'm', 187,
-        's', 142,
-        't', 164,
-        '\t', 132,
-        ' ', 132,
-      );
+      if (lookahead == 'u') ADVANCE(180);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 133:
+    case 166:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'G') ADVANCE(120);
+      if (lookahead == 'o') ADVANCE(181);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 134:
+    case 167:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'L') ADVANCE(120);
+      if (lookahead == 'L') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 135:
+    case 168:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'a') ADVANCE(141);
-      if (lookahead == 'k') ADVANCE(154);
-      if (lookahead == 'r') ADVANCE(130);
+      if (lookahead == 'G') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 136:
+    case 169:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'a') ADVANCE(174);
+      if (lookahead == 'G') ADVANCE(140);
+      if (lookahead == 'L') ADVANCE(140);
+      if (lookahead == 'e') ADVANCE(184);
+      if (lookahead == 's') ADVANCE(185);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 137:
+    case 170:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'b') ADVANCE(145);
+      if (lookahead == 'a') ADVANCE(186);
+      if (lookahead == 'k') ADVANCE(187);
+      if (lookahead == 'r') ADVANCE(188);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 138:
+    case 171:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'b') ADVANCE(151);
+      if (lookahead == 'c') ADVANCE(189);
+      if (lookahead == 't') ADVANCE(190);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 139:
+    case 172:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'c') ADVANCE(156);
-      if (lookahead == 't') ADVANCE(161);
+      if (lookahead == 'l') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 140:
+    case 173:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'c') ADVANCE(162);
+      if (lookahead == 'g') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 141:
+    case 174:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'c') ADVANCE(163);
+      if (lookahead == 'g') ADVANCE(140);
+      if (lookahead == 'l') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 142:
+    case 175:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'c') ADVANCE(157);
-      if (lookahead == 't') ADVANCE(162);
+      if (lookahead == 'c') ADVANCE(191);
+      if (lookahead == 't') ADVANCE(192);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 143:
+    case 176:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'd') ADVANCE(120);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(127);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 144:
+    case 177:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(120);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(132);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 145:
+    case 178:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(127);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(136);
+      if ((0xbc <= lookahead && lookahead <= 0xbe) ||
+          (0x2153 <= lookahead && lookahead <= 0x215e)) ADVANCE(135);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 146:
+    case 179:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(172);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(133);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 147:
+    case 180:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(158);
+      if (lookahead == 'n') ADVANCE(193);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 148:
+    case 181:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(165);
+      if (lookahead == 's') ADVANCE(194);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 149:
+    case 182:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(179);
-      if (lookahead == 's') ADVANCE(170);
-      if (lookahead == 'G' ||
-          lookahead == 'L') ADVANCE(120);
+      if (lookahead == 'a') ADVANCE(195);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 150:
+    case 183:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(173);
+      if (lookahead == 't') ADVANCE(196);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 151:
+    case 184:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(166);
+      if (lookahead == 's') ADVANCE(197);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 152:
+    case 185:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'e') ADVANCE(160);
+      if (lookahead == 'p') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 153:
+    case 186:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'g') ADVANCE(120);
+      if (lookahead == 'c') ADVANCE(198);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 154:
+    case 187:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'g') ADVANCE(131);
+      if (lookahead == 'g') ADVANCE(199);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 155:
+    case 188:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'g') ADVANCE(121);
+      if (lookahead == '.') ADVANCE(140);
+      if (lookahead == 'i') ADVANCE(200);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 156:
+    case 189:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'h') ADVANCE(147);
+      if (lookahead == 'h') ADVANCE(201);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 157:
+    case 190:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'h') ADVANCE(152);
+      if (lookahead == 'i') ADVANCE(202);
+      if (lookahead == 'k') ADVANCE(140);
+      if (lookahead == 0xfc) ADVANCE(203);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 158:
+    case 191:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'i') ADVANCE(138);
+      if (lookahead == 'h') ADVANCE(204);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 159:
+    case 192:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'i') ADVANCE(180);
+      if (lookahead == 'k') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 160:
+    case 193:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'i') ADVANCE(137);
+      if (lookahead == 'd') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 161:
+    case 194:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'i') ADVANCE(148);
-      if (lookahead == 'k') ADVANCE(120);
-      if (lookahead == 0xfc) ADVANCE(140);
+      if (lookahead == 'e') ADVANCE(143);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 162:
+    case 195:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'k') ADVANCE(120);
+      if (lookahead == 's') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 163:
+    case 196:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'k') ADVANCE(183);
+      if (lookahead == 'e') ADVANCE(206);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 164:
+    case 197:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'l') ADVANCE(120);
+      if (lookahead == 's') ADVANCE(207);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 165:
+    case 198:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'l') ADVANCE(144);
+      if (lookahead == 'k') ADVANCE(208);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 166:
+    case 199:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'n') ADVANCE(120);
+      if (lookahead == '.') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 167:
+    case 200:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'n') ADVANCE(143);
+      if (lookahead == 's') ADVANCE(209);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 168:
+    case 201:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'n') ADVANCE(155);
+      if (lookahead == 'e') ADVANCE(210);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 169:
+    case 202:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'o') ADVANCE(175);
+      if (lookahead == 'e') ADVANCE(211);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 170:
+    case 203:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'p') ADVANCE(120);
+      if (lookahead == 'c') ADVANCE(192);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 171:
+    case 204:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'p') ADVANCE(159);
+      if (lookahead == 'e') ADVANCE(212);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 172:
+    case 205:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'r') ADVANCE(120);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(137);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 173:
+    case 206:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'r') ADVANCE(176);
+      if (lookahead == 'r') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 174:
+    case 207:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 's') ADVANCE(120);
+      if (lookahead == 'e') ADVANCE(213);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 175:
+    case 208:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 's') ADVANCE(145);
+      if (lookahead == 'u') ADVANCE(214);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 176:
+    case 209:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 's') ADVANCE(171);
+      if (lookahead == 'e') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 177:
+    case 210:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 's') ADVANCE(150);
+      if (lookahead == 'i') ADVANCE(215);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 178:
+    case 211:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 's') ADVANCE(144);
+      if (lookahead == 'l') ADVANCE(209);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 179:
+    case 212:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 's') ADVANCE(177);
+      if (lookahead == 'i') ADVANCE(216);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 180:
+    case 213:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 't') ADVANCE(184);
+      if (lookahead == 'r') ADVANCE(217);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 181:
+    case 214:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 't') ADVANCE(146);
+      if (lookahead == 'n') ADVANCE(218);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 182:
+    case 215:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'u') ADVANCE(167);
+      if (lookahead == 'b') ADVANCE(219);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 183:
+    case 216:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'u') ADVANCE(168);
+      if (lookahead == 'b') ADVANCE(194);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 184:
+    case 217:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'z') ADVANCE(144);
+      if (lookahead == 's') ADVANCE(220);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 185:
+    case 218:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(185);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(117);
+      if (lookahead == 'g') ADVANCE(145);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 186:
+    case 219:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(186);
+      if (lookahead == 'e') ADVANCE(221);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 187:
+    case 220:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (lookahead == 'g' ||
-          lookahead == 'l') ADVANCE(120);
+      if (lookahead == 'p') ADVANCE(222);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 188:
+    case 221:
       ACCEPT_TOKEN(sym_ingredient_name);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(119);
+      if (lookahead == 'n') ADVANCE(140);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 189:
+    case 222:
       ACCEPT_TOKEN(sym_ingredient_name);
+      if (lookahead == 'i') ADVANCE(223);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '\r' &&
-          lookahead != '-') ADVANCE(189);
+          lookahead != '-') ADVANCE(163);
       END_STATE();
-    case 190:
+    case 223:
+      ACCEPT_TOKEN(sym_ingredient_name);
+      if (lookahead == 't') ADVANCE(224);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r' &&
+          lookahead != '-') ADVANCE(163);
+      END_STATE();
+    case 224:
+      ACCEPT_TOKEN(sym_ingredient_name);
+      if (lookahead == 'z') ADVANCE(209);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '\r' &&
+          lookahead != '-') ADVANCE(163);
+      END_STATE();
+    case 225:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == ' ') ADVANCE(197);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 191:
+    case 226:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == ' ') ADVANCE(198);
+      if (lookahead == '#') ADVANCE(229);
+      if (lookahead == '-') ADVANCE(122);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(226);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 192:
+    case 227:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == '#') ADVANCE(215);
-      if (lookahead == '-') ADVANCE(115);
+      if (lookahead == '#') ADVANCE(230);
+      if (lookahead == '-') ADVANCE(225);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(192);
+          lookahead == ' ') ADVANCE(227);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 193:
+    case 228:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == '#') ADVANCE(194);
-      if (lookahead == '-') ADVANCE(115);
+      if (lookahead == '#') ADVANCE(225);
+      if (lookahead == '-') ADVANCE(122);
       if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(193);
+          lookahead == ' ') ADVANCE(228);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 194:
+    case 229:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == '#') ADVANCE(190);
+      if (lookahead == '#') ADVANCE(231);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 195:
+    case 230:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == '#') ADVANCE(191);
+      if (lookahead == '#') ADVANCE(232);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 196:
+    case 231:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == '#') ADVANCE(195);
-      if (lookahead == '-') ADVANCE(215);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(196);
+      if (lookahead == ' ') ADVANCE(233);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 197:
+    case 232:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'Z') ADVANCE(212);
+      if (lookahead == ' ') ADVANCE(234);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 198:
+    case 233:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'Z') ADVANCE(214);
+      if (lookahead == 'Z') ADVANCE(235);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 199:
+    case 234:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'a') ADVANCE(211);
+      if (lookahead == 'Z') ADVANCE(236);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 200:
+    case 235:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'b') ADVANCE(201);
+      if (lookahead == 'u') ADVANCE(237);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 201:
+    case 236:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'e') ADVANCE(208);
+      if (lookahead == 'u') ADVANCE(238);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 202:
+    case 237:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'e') ADVANCE(205);
+      if (lookahead == 'b') ADVANCE(239);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 203:
+    case 238:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'e') ADVANCE(207);
+      if (lookahead == 't') ADVANCE(240);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 204:
+    case 239:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'g') ADVANCE(113);
+      if (lookahead == 'e') ADVANCE(241);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 205:
+    case 240:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'i') ADVANCE(210);
+      if (lookahead == 'a') ADVANCE(242);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 206:
+    case 241:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'n') ADVANCE(204);
+      if (lookahead == 'r') ADVANCE(243);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 207:
+    case 242:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'n') ADVANCE(111);
+      if (lookahead == 't') ADVANCE(244);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 208:
+    case 243:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'r') ADVANCE(202);
+      if (lookahead == 'e') ADVANCE(245);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 209:
+    case 244:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 't') ADVANCE(199);
+      if (lookahead == 'e') ADVANCE(246);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 210:
+    case 245:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 't') ADVANCE(213);
+      if (lookahead == 'i') ADVANCE(247);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 211:
+    case 246:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 't') ADVANCE(203);
+      if (lookahead == 'n') ADVANCE(118);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 212:
+    case 247:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'u') ADVANCE(200);
+      if (lookahead == 't') ADVANCE(248);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 213:
+    case 248:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'u') ADVANCE(206);
+      if (lookahead == 'u') ADVANCE(249);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 214:
+    case 249:
       ACCEPT_TOKEN(sym_preamble_line);
-      if (lookahead == 'u') ADVANCE(209);
+      if (lookahead == 'n') ADVANCE(250);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
-    case 215:
+    case 250:
       ACCEPT_TOKEN(sym_preamble_line);
+      if (lookahead == 'g') ADVANCE(120);
       if (lookahead != 0 &&
           lookahead != '\n' &&
-          lookahead != '\r') ADVANCE(215);
+          lookahead != '\r') ADVANCE(225);
       END_STATE();
     default:
       return false;
@@ -1506,32 +1845,35 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
 
 static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0},
-  [1] = {.lex_state = 102},
-  [2] = {.lex_state = 104},
-  [3] = {.lex_state = 104},
-  [4] = {.lex_state = 104},
-  [5] = {.lex_state = 1},
-  [6] = {.lex_state = 2},
-  [7] = {.lex_state = 3},
-  [8] = {.lex_state = 1},
-  [9] = {.lex_state = 104},
-  [10] = {.lex_state = 104},
-  [11] = {.lex_state = 104},
-  [12] = {.lex_state = 0},
+  [1] = {.lex_state = 1},
+  [2] = {.lex_state = 2},
+  [3] = {.lex_state = 3},
+  [4] = {.lex_state = 3},
+  [5] = {.lex_state = 4},
+  [6] = {.lex_state = 5},
+  [7] = {.lex_state = 4},
+  [8] = {.lex_state = 3},
+  [9] = {.lex_state = 3},
+  [10] = {.lex_state = 3},
+  [11] = {.lex_state = 3},
+  [12] = {.lex_state = 1},
   [13] = {.lex_state = 0},
-  [14] = {.lex_state = 105},
-  [15] = {.lex_state = 105},
-  [16] = {.lex_state = 105},
-  [17] = {.lex_state = 1},
-  [18] = {.lex_state = 1},
-  [19] = {.lex_state = 0},
+  [14] = {.lex_state = 6},
+  [15] = {.lex_state = 0},
+  [16] = {.lex_state = 4},
+  [17] = {.lex_state = 7},
+  [18] = {.lex_state = 6},
+  [19] = {.lex_state = 6},
   [20] = {.lex_state = 4},
   [21] = {.lex_state = 4},
-  [22] = {.lex_state = 0},
-  [23] = {.lex_state = 5},
-  [24] = {.lex_state = 0},
-  [25] = {.lex_state = 0},
-  [26] = {.lex_state = 6},
+  [22] = {.lex_state = 8},
+  [23] = {.lex_state = 8},
+  [24] = {.lex_state = 8},
+  [25] = {.lex_state = 9},
+  [26] = {.lex_state = 0},
+  [27] = {.lex_state = 0},
+  [28] = {.lex_state = 0},
+  [29] = {.lex_state = 10},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -1544,251 +1886,280 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DASH] = ACTIONS(1),
     [sym_integer] = ACTIONS(1),
     [sym_float] = ACTIONS(1),
+    [sym_fraction] = ACTIONS(1),
+    [sym_range] = ACTIONS(1),
     [sym_unit] = ACTIONS(1),
   },
   [STATE(1)] = {
-    [sym_source_file] = STATE(22),
+    [sym_source_file] = STATE(26),
     [sym_persons_line] = STATE(5),
     [sym_integer] = ACTIONS(3),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 5,
+  [0] = 4,
     ACTIONS(5), 1,
-      ts_builtin_sym_end,
-    ACTIONS(7), 1,
-      anon_sym_POUND_POUNDZubereitung,
+      sym_integer,
     ACTIONS(9), 1,
-      anon_sym_DASH,
+      sym_ingredient_name,
+    STATE(23), 1,
+      sym_quantity,
+    ACTIONS(7), 3,
+      sym_float,
+      sym_fraction,
+      sym_range,
+  [15] = 5,
     ACTIONS(11), 1,
-      sym_preamble_line,
-    STATE(3), 2,
-      sym_ingredient_line,
-      aux_sym_ingredients_section_repeat1,
-  [17] = 5,
-    ACTIONS(13), 1,
       ts_builtin_sym_end,
-    ACTIONS(15), 1,
+    ACTIONS(13), 1,
       anon_sym_POUND_POUNDZubereitung,
-    ACTIONS(17), 1,
+    ACTIONS(15), 1,
       anon_sym_DASH,
-    ACTIONS(20), 1,
+    ACTIONS(17), 1,
       sym_preamble_line,
-    STATE(3), 2,
+    STATE(4), 2,
       sym_ingredient_line,
       aux_sym_ingredients_section_repeat1,
-  [34] = 2,
-    ACTIONS(23), 1,
+  [32] = 5,
+    ACTIONS(19), 1,
       ts_builtin_sym_end,
-    ACTIONS(25), 3,
+    ACTIONS(21), 1,
       anon_sym_POUND_POUNDZubereitung,
+    ACTIONS(23), 1,
       anon_sym_DASH,
+    ACTIONS(26), 1,
       sym_preamble_line,
-  [43] = 4,
-    ACTIONS(27), 1,
-      anon_sym_POUND_POUNDZutaten,
+    STATE(4), 2,
+      sym_ingredient_line,
+      aux_sym_ingredients_section_repeat1,
+  [49] = 4,
     ACTIONS(29), 1,
+      anon_sym_POUND_POUNDZutaten,
+    ACTIONS(31), 1,
       sym_preamble_line,
-    STATE(8), 1,
+    STATE(7), 1,
       aux_sym_source_file_repeat1,
-    STATE(12), 1,
+    STATE(13), 1,
       sym_ingredients_section,
-  [56] = 3,
-    ACTIONS(33), 1,
-      sym_ingredient_name,
-    STATE(20), 1,
-      sym_quantity,
-    ACTIONS(31), 2,
-      sym_integer,
-      sym_float,
-  [67] = 3,
-    ACTIONS(9), 1,
+  [62] = 3,
+    ACTIONS(15), 1,
       anon_sym_DASH,
-    ACTIONS(11), 1,
+    ACTIONS(17), 1,
       sym_preamble_line,
-    STATE(2), 2,
+    STATE(3), 2,
       sym_ingredient_line,
       aux_sym_ingredients_section_repeat1,
-  [78] = 4,
-    ACTIONS(27), 1,
+  [73] = 4,
+    ACTIONS(29), 1,
       anon_sym_POUND_POUNDZutaten,
-    ACTIONS(35), 1,
+    ACTIONS(33), 1,
       sym_preamble_line,
-    STATE(13), 1,
+    STATE(15), 1,
       sym_ingredients_section,
-    STATE(17), 1,
+    STATE(16), 1,
       aux_sym_source_file_repeat1,
-  [91] = 2,
-    ACTIONS(37), 1,
+  [86] = 2,
+    ACTIONS(35), 1,
       ts_builtin_sym_end,
-    ACTIONS(39), 3,
+    ACTIONS(37), 3,
       anon_sym_POUND_POUNDZubereitung,
       anon_sym_DASH,
       sym_preamble_line,
-  [100] = 2,
-    ACTIONS(41), 1,
+  [95] = 2,
+    ACTIONS(39), 1,
       ts_builtin_sym_end,
-    ACTIONS(43), 3,
+    ACTIONS(41), 3,
       anon_sym_POUND_POUNDZubereitung,
       anon_sym_DASH,
       sym_preamble_line,
-  [109] = 2,
-    ACTIONS(45), 1,
+  [104] = 2,
+    ACTIONS(43), 1,
       ts_builtin_sym_end,
-    ACTIONS(47), 3,
+    ACTIONS(45), 3,
       anon_sym_POUND_POUNDZubereitung,
       anon_sym_DASH,
       sym_preamble_line,
-  [118] = 3,
-    ACTIONS(49), 1,
+  [113] = 2,
+    ACTIONS(47), 1,
       ts_builtin_sym_end,
-    ACTIONS(51), 1,
-      anon_sym_POUND_POUNDZubereitung,
-    STATE(25), 1,
-      sym_preparation_section,
-  [128] = 3,
-    ACTIONS(51), 1,
+    ACTIONS(49), 3,
       anon_sym_POUND_POUNDZubereitung,
+      anon_sym_DASH,
+      sym_preamble_line,
+  [122] = 2,
     ACTIONS(53), 1,
-      ts_builtin_sym_end,
-    STATE(24), 1,
-      sym_preparation_section,
-  [138] = 3,
+      sym_batch_unit,
+    ACTIONS(51), 2,
+      anon_sym_Personen,
+      anon_sym_Portionen,
+  [130] = 3,
     ACTIONS(55), 1,
       ts_builtin_sym_end,
     ACTIONS(57), 1,
-      sym_text,
-    STATE(15), 1,
-      aux_sym_preparation_section_repeat1,
-  [148] = 3,
+      anon_sym_POUND_POUNDZubereitung,
+    STATE(27), 1,
+      sym_preparation_section,
+  [140] = 3,
     ACTIONS(59), 1,
       ts_builtin_sym_end,
     ACTIONS(61), 1,
       sym_text,
-    STATE(16), 1,
+    STATE(18), 1,
       aux_sym_preparation_section_repeat1,
-  [158] = 3,
+  [150] = 3,
+    ACTIONS(57), 1,
+      anon_sym_POUND_POUNDZubereitung,
     ACTIONS(63), 1,
       ts_builtin_sym_end,
+    STATE(28), 1,
+      sym_preparation_section,
+  [160] = 3,
     ACTIONS(65), 1,
-      sym_text,
+      anon_sym_POUND_POUNDZutaten,
+    ACTIONS(67), 1,
+      sym_preamble_line,
     STATE(16), 1,
+      aux_sym_source_file_repeat1,
+  [170] = 2,
+    ACTIONS(70), 1,
+      sym_fraction,
+    ACTIONS(72), 2,
+      sym_unit,
+      sym_ingredient_name,
+  [178] = 3,
+    ACTIONS(74), 1,
+      ts_builtin_sym_end,
+    ACTIONS(76), 1,
+      sym_text,
+    STATE(19), 1,
       aux_sym_preparation_section_repeat1,
-  [168] = 3,
-    ACTIONS(68), 1,
+  [188] = 3,
+    ACTIONS(78), 1,
+      ts_builtin_sym_end,
+    ACTIONS(80), 1,
+      sym_text,
+    STATE(19), 1,
+      aux_sym_preparation_section_repeat1,
+  [198] = 1,
+    ACTIONS(83), 2,
       anon_sym_POUND_POUNDZutaten,
-    ACTIONS(70), 1,
       sym_preamble_line,
-    STATE(17), 1,
-      aux_sym_source_file_repeat1,
-  [178] = 1,
-    ACTIONS(73), 2,
+  [203] = 1,
+    ACTIONS(85), 2,
       anon_sym_POUND_POUNDZutaten,
       sym_preamble_line,
-  [183] = 1,
-    ACTIONS(75), 2,
-      anon_sym_Personen,
-      anon_sym_Portionen,
-  [188] = 2,
-    ACTIONS(77), 1,
+  [208] = 1,
+    ACTIONS(72), 2,
       sym_unit,
-    ACTIONS(79), 1,
       sym_ingredient_name,
-  [195] = 1,
-    ACTIONS(81), 2,
+  [213] = 2,
+    ACTIONS(87), 1,
       sym_unit,
+    ACTIONS(89), 1,
       sym_ingredient_name,
-  [200] = 1,
-    ACTIONS(83), 1,
-      ts_builtin_sym_end,
-  [204] = 1,
-    ACTIONS(85), 1,
+  [220] = 1,
+    ACTIONS(91), 2,
+      sym_unit,
+      sym_ingredient_name,
+  [225] = 1,
+    ACTIONS(93), 1,
       aux_sym_persons_line_token1,
-  [208] = 1,
-    ACTIONS(87), 1,
+  [229] = 1,
+    ACTIONS(95), 1,
       ts_builtin_sym_end,
-  [212] = 1,
-    ACTIONS(53), 1,
+  [233] = 1,
+    ACTIONS(63), 1,
       ts_builtin_sym_end,
-  [216] = 1,
-    ACTIONS(89), 1,
+  [237] = 1,
+    ACTIONS(97), 1,
+      ts_builtin_sym_end,
+  [241] = 1,
+    ACTIONS(99), 1,
       sym_ingredient_name,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 17,
-  [SMALL_STATE(4)] = 34,
-  [SMALL_STATE(5)] = 43,
-  [SMALL_STATE(6)] = 56,
-  [SMALL_STATE(7)] = 67,
-  [SMALL_STATE(8)] = 78,
-  [SMALL_STATE(9)] = 91,
-  [SMALL_STATE(10)] = 100,
-  [SMALL_STATE(11)] = 109,
-  [SMALL_STATE(12)] = 118,
-  [SMALL_STATE(13)] = 128,
-  [SMALL_STATE(14)] = 138,
-  [SMALL_STATE(15)] = 148,
-  [SMALL_STATE(16)] = 158,
-  [SMALL_STATE(17)] = 168,
+  [SMALL_STATE(3)] = 15,
+  [SMALL_STATE(4)] = 32,
+  [SMALL_STATE(5)] = 49,
+  [SMALL_STATE(6)] = 62,
+  [SMALL_STATE(7)] = 73,
+  [SMALL_STATE(8)] = 86,
+  [SMALL_STATE(9)] = 95,
+  [SMALL_STATE(10)] = 104,
+  [SMALL_STATE(11)] = 113,
+  [SMALL_STATE(12)] = 122,
+  [SMALL_STATE(13)] = 130,
+  [SMALL_STATE(14)] = 140,
+  [SMALL_STATE(15)] = 150,
+  [SMALL_STATE(16)] = 160,
+  [SMALL_STATE(17)] = 170,
   [SMALL_STATE(18)] = 178,
-  [SMALL_STATE(19)] = 183,
-  [SMALL_STATE(20)] = 188,
-  [SMALL_STATE(21)] = 195,
-  [SMALL_STATE(22)] = 200,
-  [SMALL_STATE(23)] = 204,
-  [SMALL_STATE(24)] = 208,
-  [SMALL_STATE(25)] = 212,
-  [SMALL_STATE(26)] = 216,
+  [SMALL_STATE(19)] = 188,
+  [SMALL_STATE(20)] = 198,
+  [SMALL_STATE(21)] = 203,
+  [SMALL_STATE(22)] = 208,
+  [SMALL_STATE(23)] = 213,
+  [SMALL_STATE(24)] = 220,
+  [SMALL_STATE(25)] = 225,
+  [SMALL_STATE(26)] = 229,
+  [SMALL_STATE(27)] = 233,
+  [SMALL_STATE(28)] = 237,
+  [SMALL_STATE(29)] = 241,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredients_section, 2, 0, 0),
-  [7] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredients_section, 2, 0, 0),
-  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
-  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(4),
-  [13] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0),
-  [15] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0),
-  [17] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0), SHIFT_REPEAT(6),
-  [20] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0), SHIFT_REPEAT(4),
-  [23] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 1, 0, 0),
-  [25] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 1, 0, 0),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
-  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 3, 0, 3),
-  [39] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 3, 0, 3),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 2, 0, 2),
-  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 2, 0, 2),
-  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 4, 0, 4),
-  [47] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 4, 0, 4),
-  [49] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [53] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
-  [55] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preparation_section, 1, 0, 0),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preparation_section, 2, 0, 0),
-  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [63] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_preparation_section_repeat1, 2, 0, 0),
-  [65] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_preparation_section_repeat1, 2, 0, 0), SHIFT_REPEAT(16),
-  [68] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [70] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(17),
-  [73] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_persons_line, 3, 0, 1),
-  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [77] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
-  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
-  [81] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quantity, 1, 0, 0),
-  [83] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [87] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 0),
-  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [5] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
+  [7] = {.entry = {.count = 1, .reusable = false}}, SHIFT(22),
+  [9] = {.entry = {.count = 1, .reusable = false}}, SHIFT(9),
+  [11] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredients_section, 2, 0, 0),
+  [13] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredients_section, 2, 0, 0),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(2),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(8),
+  [19] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0),
+  [21] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0),
+  [23] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0), SHIFT_REPEAT(2),
+  [26] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_ingredients_section_repeat1, 2, 0, 0), SHIFT_REPEAT(8),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(6),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(7),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(16),
+  [35] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 1, 0, 0),
+  [37] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 1, 0, 0),
+  [39] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 2, 0, 3),
+  [41] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 2, 0, 3),
+  [43] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 3, 0, 4),
+  [45] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 3, 0, 4),
+  [47] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ingredient_line, 4, 0, 5),
+  [49] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ingredient_line, 4, 0, 5),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(20),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [55] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2, 0, 0),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preparation_section, 1, 0, 0),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [63] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
+  [65] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [67] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(16),
+  [70] = {.entry = {.count = 1, .reusable = false}}, SHIFT(24),
+  [72] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quantity, 1, 0, 0),
+  [74] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_preparation_section, 2, 0, 0),
+  [76] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [78] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_preparation_section_repeat1, 2, 0, 0),
+  [80] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_preparation_section_repeat1, 2, 0, 0), SHIFT_REPEAT(19),
+  [83] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_persons_line, 3, 0, 1),
+  [85] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_persons_line, 3, 0, 2),
+  [87] = {.entry = {.count = 1, .reusable = false}}, SHIFT(29),
+  [89] = {.entry = {.count = 1, .reusable = false}}, SHIFT(10),
+  [91] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_quantity, 2, 0, 0),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [95] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [97] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 0),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
 };
 
 #ifdef __cplusplus
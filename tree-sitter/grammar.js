@@ -29,7 +29,16 @@ module.exports = grammar({
     // --------------------
 
     persons_line: $ =>
-      seq("Personen:", field("count", $.integer)),
+      seq("Personen:", field("count", $.integer), optional(field("groups", $.groups))),
+
+    // Participant groups with a diet, e.g. "(vegan 12, vegetarisch 8)"
+    groups: $ =>
+      seq("(", $.group, repeat(seq(",", $.group)), ")"),
+
+    group: $ =>
+      seq(field("diet", $.diet), field("count", $.integer)),
+
+    diet: _ => /[^\s\d(),]+/,
 
     starttag_line: $ =>
       seq("Starttag:", field("date", $.date)),
@@ -7,54 +7,64 @@
 #endif
 
 #define LANGUAGE_VERSION 15
-#define STATE_COUNT 36
+#define STATE_COUNT 47
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 30
+#define SYMBOL_COUNT 36
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 17
+#define TOKEN_COUNT 20
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 5
+#define FIELD_COUNT 7
 #define MAX_ALIAS_SEQUENCE_LENGTH 5
 #define MAX_RESERVED_WORD_SET_SIZE 0
-#define PRODUCTION_ID_COUNT 9
+#define PRODUCTION_ID_COUNT 11
 #define SUPERTYPE_COUNT 0
 
 enum ts_symbol_identifiers {
   anon_sym_LF = 1,
   anon_sym_Personen_COLON = 2,
-  anon_sym_Starttag_COLON = 3,
-  sym_integer = 4,
-  sym_date = 5,
-  anon_sym_COLON = 6,
-  sym_day_name = 7,
-  sym_count = 8,
-  sym_rest_day = 9,
-  anon_sym_COMMA = 10,
-  anon_sym_u27e8 = 11,
-  aux_sym_shopping_marker_token1 = 12,
-  anon_sym_u27e9 = 13,
-  anon_sym_LBRACK_LBRACK = 14,
-  aux_sym_dish_token1 = 15,
-  anon_sym_RBRACK_RBRACK = 16,
-  sym_source_file = 17,
-  sym_persons_line = 18,
-  sym_starttag_line = 19,
-  sym_day_line = 20,
-  sym_day_with_count = 21,
-  sym_menu = 22,
-  sym_menu_items = 23,
-  sym_menu_item = 24,
-  sym_shopping_marker = 25,
-  sym_dish_with_count = 26,
-  sym_dish = 27,
-  aux_sym_source_file_repeat1 = 28,
-  aux_sym_menu_items_repeat1 = 29,
+  anon_sym_LPAREN = 3,
+  anon_sym_COMMA = 4,
+  anon_sym_RPAREN = 5,
+  sym_diet = 6,
+  anon_sym_Starttag_COLON = 7,
+  sym_integer = 8,
+  sym_date = 9,
+  anon_sym_COLON = 10,
+  sym_day_name = 11,
+  sym_count = 12,
+  sym_rest_day = 13,
+  anon_sym_u27e8 = 14,
+  aux_sym_shopping_marker_token1 = 15,
+  anon_sym_u27e9 = 16,
+  anon_sym_LBRACK_LBRACK = 17,
+  aux_sym_dish_token1 = 18,
+  anon_sym_RBRACK_RBRACK = 19,
+  sym_source_file = 20,
+  sym_persons_line = 21,
+  sym_groups = 22,
+  sym_group = 23,
+  sym_starttag_line = 24,
+  sym_day_line = 25,
+  sym_day_with_count = 26,
+  sym_menu = 27,
+  sym_menu_items = 28,
+  sym_menu_item = 29,
+  sym_shopping_marker = 30,
+  sym_dish_with_count = 31,
+  sym_dish = 32,
+  aux_sym_source_file_repeat1 = 33,
+  aux_sym_groups_repeat1 = 34,
+  aux_sym_menu_items_repeat1 = 35,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
   [anon_sym_LF] = "\n",
   [anon_sym_Personen_COLON] = "Personen:",
+  [anon_sym_LPAREN] = "(",
+  [anon_sym_COMMA] = ",",
+  [anon_sym_RPAREN] = ")",
+  [sym_diet] = "diet",
   [anon_sym_Starttag_COLON] = "Starttag:",
   [sym_integer] = "integer",
   [sym_date] = "date",
@@ -62,7 +72,6 @@ static const char * const ts_symbol_names[] = {
   [sym_day_name] = "day_name",
   [sym_count] = "count",
   [sym_rest_day] = "rest_day",
-  [anon_sym_COMMA] = ",",
   [anon_sym_u27e8] = "\u27e8",
   [aux_sym_shopping_marker_token1] = "shopping_marker_token1",
   [anon_sym_u27e9] = "\u27e9",
@@ -71,6 +80,8 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_RBRACK_RBRACK] = "]]",
   [sym_source_file] = "source_file",
   [sym_persons_line] = "persons_line",
+  [sym_groups] = "groups",
+  [sym_group] = "group",
   [sym_starttag_line] = "starttag_line",
   [sym_day_line] = "day_line",
   [sym_day_with_count] = "day_with_count",
@@ -81,6 +92,7 @@ static const char * const ts_symbol_names[] = {
   [sym_dish_with_count] = "dish_with_count",
   [sym_dish] = "dish",
   [aux_sym_source_file_repeat1] = "source_file_repeat1",
+  [aux_sym_groups_repeat1] = "groups_repeat1",
   [aux_sym_menu_items_repeat1] = "menu_items_repeat1",
 };
 
@@ -88,6 +100,10 @@ static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
   [anon_sym_LF] = anon_sym_LF,
   [anon_sym_Personen_COLON] = anon_sym_Personen_COLON,
+  [anon_sym_LPAREN] = anon_sym_LPAREN,
+  [anon_sym_COMMA] = anon_sym_COMMA,
+  [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [sym_diet] = sym_diet,
   [anon_sym_Starttag_COLON] = anon_sym_Starttag_COLON,
   [sym_integer] = sym_integer,
   [sym_date] = sym_date,
@@ -95,7 +111,6 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_day_name] = sym_day_name,
   [sym_count] = sym_count,
   [sym_rest_day] = sym_rest_day,
-  [anon_sym_COMMA] = anon_sym_COMMA,
   [anon_sym_u27e8] = anon_sym_u27e8,
   [aux_sym_shopping_marker_token1] = aux_sym_shopping_marker_token1,
   [anon_sym_u27e9] = anon_sym_u27e9,
@@ -104,6 +119,8 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_RBRACK_RBRACK] = anon_sym_RBRACK_RBRACK,
   [sym_source_file] = sym_source_file,
   [sym_persons_line] = sym_persons_line,
+  [sym_groups] = sym_groups,
+  [sym_group] = sym_group,
   [sym_starttag_line] = sym_starttag_line,
   [sym_day_line] = sym_day_line,
   [sym_day_with_count] = sym_day_with_count,
@@ -114,6 +131,7 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_dish_with_count] = sym_dish_with_count,
   [sym_dish] = sym_dish,
   [aux_sym_source_file_repeat1] = aux_sym_source_file_repeat1,
+  [aux_sym_groups_repeat1] = aux_sym_groups_repeat1,
   [aux_sym_menu_items_repeat1] = aux_sym_menu_items_repeat1,
 };
 
@@ -130,6 +148,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_LPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COMMA] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_RPAREN] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_diet] = {
+    .visible = true,
+    .named = true,
+  },
   [anon_sym_Starttag_COLON] = {
     .visible = true,
     .named = false,
@@ -158,10 +192,6 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
-  [anon_sym_COMMA] = {
-    .visible = true,
-    .named = false,
-  },
   [anon_sym_u27e8] = {
     .visible = true,
     .named = false,
@@ -194,6 +224,14 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_groups] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_group] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_starttag_line] = {
     .visible = true,
     .named = true,
@@ -234,6 +272,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_groups_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_menu_items_repeat1] = {
     .visible = false,
     .named = false,
@@ -244,8 +286,10 @@ enum ts_field_identifiers {
   field_count = 1,
   field_date = 2,
   field_day = 3,
-  field_dish = 4,
-  field_name = 5,
+  field_diet = 4,
+  field_dish = 5,
+  field_groups = 6,
+  field_name = 7,
 };
 
 static const char * const ts_field_names[] = {
@@ -253,39 +297,49 @@ static const char * const ts_field_names[] = {
   [field_count] = "count",
   [field_date] = "date",
   [field_day] = "day",
+  [field_diet] = "diet",
   [field_dish] = "dish",
+  [field_groups] = "groups",
   [field_name] = "name",
 };
 
 static const TSMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
-  [2] = {.index = 1, .length = 1},
-  [3] = {.index = 2, .length = 1},
-  [4] = {.index = 3, .length = 2},
-  [5] = {.index = 5, .length = 1},
-  [6] = {.index = 6, .length = 1},
-  [7] = {.index = 7, .length = 2},
-  [8] = {.index = 9, .length = 1},
+  [2] = {.index = 1, .length = 2},
+  [3] = {.index = 3, .length = 1},
+  [4] = {.index = 4, .length = 2},
+  [5] = {.index = 6, .length = 1},
+  [6] = {.index = 7, .length = 2},
+  [7] = {.index = 9, .length = 1},
+  [8] = {.index = 10, .length = 1},
+  [9] = {.index = 11, .length = 2},
+  [10] = {.index = 13, .length = 1},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
   [0] =
     {field_count, 1},
   [1] =
+    {field_count, 1},
+    {field_groups, 2},
+  [3] =
     {field_date, 1},
-  [2] =
+  [4] =
+    {field_count, 1},
+    {field_diet, 0},
+  [6] =
     {field_name, 0},
-  [3] =
+  [7] =
     {field_count, 1},
     {field_name, 0},
-  [5] =
+  [9] =
     {field_day, 0},
-  [6] =
+  [10] =
     {field_dish, 0},
-  [7] =
+  [11] =
     {field_count, 1},
     {field_dish, 0},
-  [9] =
+  [13] =
     {field_name, 1},
 };
 
@@ -334,6 +388,17 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [33] = 33,
   [34] = 34,
   [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -344,152 +409,165 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       if (eof) ADVANCE(39);
       ADVANCE_MAP(
         '\n', 40,
-        '(', 30,
-        ',', 54,
-        ':', 49,
-        'P', 10,
-        'R', 11,
-        'S', 22,
-        '[', 6,
-        ']', 7,
-        0x27e8, 55,
-        0x27e9, 58,
+        '(', 42,
+        ')', 45,
+        ',', 44,
+        ':', 54,
+        'P', 6,
+        'R', 7,
+        'S', 8,
+        '[', 9,
+        ']', 10,
+        0x27e8, 59,
+        0x27e9, 62,
       );
       if (lookahead == '\t' ||
           lookahead == ' ') SKIP(0);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(46);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(48);
       END_STATE();
     case 1:
-      if (lookahead == ')') ADVANCE(52);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(1);
+      if (eof) ADVANCE(39);
+      if (lookahead == '\n') ADVANCE(40);
+      if (lookahead == '(') ADVANCE(11);
+      if (lookahead == ',') ADVANCE(44);
+      if (lookahead == ':') ADVANCE(54);
+      if (lookahead == '[') ADVANCE(9);
+      if (lookahead == 0x27e8) ADVANCE(59);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(1);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
       END_STATE();
     case 2:
-      if (lookahead == '-') ADVANCE(33);
+      if (eof) ADVANCE(39);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(56);
+      if (lookahead != 0 &&
+          lookahead != '\n' &&
+          lookahead != '(' &&
+          lookahead != ':') ADVANCE(55);
       END_STATE();
     case 3:
-      if (lookahead == '-') ADVANCE(34);
+      if (lookahead == '\n') ADVANCE(40);
+      if (lookahead == '(') ADVANCE(43);
+      if (lookahead == '\t' ||
+          lookahead == ' ') SKIP(3);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(12);
+      if (lookahead != 0 &&
+          (lookahead < '\v' || '\r' < lookahead) &&
+          lookahead != ')' &&
+          lookahead != ',') ADVANCE(46);
       END_STATE();
     case 4:
-      if (lookahead == ':') ADVANCE(41);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(61);
+      if (lookahead != 0 &&
+          lookahead != 0x27e9) ADVANCE(60);
       END_STATE();
     case 5:
-      if (lookahead == ':') ADVANCE(42);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(65);
+      if (lookahead != 0 &&
+          lookahead != ']') ADVANCE(64);
       END_STATE();
     case 6:
-      if (lookahead == '[') ADVANCE(59);
+      if (lookahead == 'e') ADVANCE(14);
       END_STATE();
     case 7:
-      if (lookahead == ']') ADVANCE(62);
+      if (lookahead == 'e') ADVANCE(15);
       END_STATE();
     case 8:
-      if (lookahead == 'a') ADVANCE(14);
+      if (lookahead == 't') ADVANCE(16);
       END_STATE();
     case 9:
-      if (lookahead == 'a') ADVANCE(19);
+      if (lookahead == '[') ADVANCE(63);
       END_STATE();
     case 10:
-      if (lookahead == 'e') ADVANCE(18);
+      if (lookahead == ']') ADVANCE(66);
       END_STATE();
     case 11:
-      if (lookahead == 'e') ADVANCE(21);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(13);
       END_STATE();
     case 12:
-      if (lookahead == 'e') ADVANCE(53);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(17);
       END_STATE();
     case 13:
-      if (lookahead == 'e') ADVANCE(15);
+      if (lookahead == ')') ADVANCE(57);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(13);
       END_STATE();
     case 14:
-      if (lookahead == 'g') ADVANCE(5);
+      if (lookahead == 'r') ADVANCE(18);
       END_STATE();
     case 15:
-      if (lookahead == 'n') ADVANCE(4);
+      if (lookahead == 's') ADVANCE(19);
       END_STATE();
     case 16:
-      if (lookahead == 'n') ADVANCE(13);
+      if (lookahead == 'a') ADVANCE(20);
       END_STATE();
     case 17:
-      if (lookahead == 'o') ADVANCE(16);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(21);
       END_STATE();
     case 18:
-      if (lookahead == 'r') ADVANCE(20);
+      if (lookahead == 's') ADVANCE(22);
       END_STATE();
     case 19:
-      if (lookahead == 'r') ADVANCE(25);
+      if (lookahead == 't') ADVANCE(23);
       END_STATE();
     case 20:
-      if (lookahead == 's') ADVANCE(17);
+      if (lookahead == 'r') ADVANCE(24);
       END_STATE();
     case 21:
-      if (lookahead == 's') ADVANCE(24);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(25);
       END_STATE();
     case 22:
-      if (lookahead == 't') ADVANCE(9);
+      if (lookahead == 'o') ADVANCE(27);
       END_STATE();
     case 23:
-      if (lookahead == 't') ADVANCE(8);
+      if (lookahead == 'e') ADVANCE(58);
       END_STATE();
     case 24:
-      if (lookahead == 't') ADVANCE(12);
+      if (lookahead == 't') ADVANCE(28);
       END_STATE();
     case 25:
-      if (lookahead == 't') ADVANCE(23);
+      if (lookahead == '-') ADVANCE(26);
       END_STATE();
     case 26:
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(56);
-      if (lookahead != 0 &&
-          lookahead != 0x27e9) ADVANCE(57);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(29);
       END_STATE();
     case 27:
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(60);
-      if (lookahead != 0 &&
-          lookahead != ']') ADVANCE(61);
+      if (lookahead == 'n') ADVANCE(30);
       END_STATE();
     case 28:
-      if (lookahead == '\t' ||
-          lookahead == ' ') SKIP(28);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(37);
+      if (lookahead == 't') ADVANCE(31);
       END_STATE();
     case 29:
-      if (lookahead == '\t' ||
-          lookahead == ' ') SKIP(29);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(32);
       END_STATE();
     case 30:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(1);
+      if (lookahead == 'e') ADVANCE(33);
       END_STATE();
     case 31:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(3);
+      if (lookahead == 'a') ADVANCE(34);
       END_STATE();
     case 32:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(48);
+      if (lookahead == '-') ADVANCE(35);
       END_STATE();
     case 33:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(31);
+      if (lookahead == 'n') ADVANCE(36);
       END_STATE();
     case 34:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(32);
+      if (lookahead == 'g') ADVANCE(37);
       END_STATE();
     case 35:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(2);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(38);
       END_STATE();
     case 36:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(35);
+      if (lookahead == ':') ADVANCE(41);
       END_STATE();
     case 37:
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(36);
+      if (lookahead == ':') ADVANCE(47);
       END_STATE();
     case 38:
-      if (eof) ADVANCE(39);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(50);
-      if (lookahead != 0 &&
-          lookahead != '\t' &&
-          lookahead != '\n' &&
-          lookahead != '(' &&
-          lookahead != ':') ADVANCE(51);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(53);
       END_STATE();
     case 39:
       ACCEPT_TOKEN(ts_builtin_sym_end);
@@ -501,95 +579,113 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
       ACCEPT_TOKEN(anon_sym_Personen_COLON);
       END_STATE();
     case 42:
-      ACCEPT_TOKEN(anon_sym_Starttag_COLON);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(13);
       END_STATE();
     case 43:
-      ACCEPT_TOKEN(sym_integer);
-      if (lookahead == '-') ADVANCE(33);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 44:
-      ACCEPT_TOKEN(sym_integer);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(43);
+      ACCEPT_TOKEN(anon_sym_COMMA);
       END_STATE();
     case 45:
-      ACCEPT_TOKEN(sym_integer);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(44);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 46:
-      ACCEPT_TOKEN(sym_integer);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(45);
+      ACCEPT_TOKEN(sym_diet);
+      if (lookahead != 0 &&
+          (lookahead < '\t' || '\r' < lookahead) &&
+          lookahead != ' ' &&
+          (lookahead < '(' || ')' < lookahead) &&
+          lookahead != ',' &&
+          (lookahead < '0' || '9' < lookahead)) ADVANCE(46);
       END_STATE();
     case 47:
-      ACCEPT_TOKEN(sym_integer);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(47);
+      ACCEPT_TOKEN(anon_sym_Starttag_COLON);
       END_STATE();
     case 48:
-      ACCEPT_TOKEN(sym_date);
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(50);
       END_STATE();
     case 49:
-      ACCEPT_TOKEN(anon_sym_COLON);
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
       END_STATE();
     case 50:
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(51);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(sym_integer);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(52);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(sym_integer);
+      if (lookahead == '-') ADVANCE(26);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(49);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(sym_date);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 55:
       ACCEPT_TOKEN(sym_day_name);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(50);
       if (lookahead != 0 &&
-          lookahead != '\t' &&
           lookahead != '\n' &&
           lookahead != '(' &&
-          lookahead != ':') ADVANCE(51);
+          lookahead != ':') ADVANCE(55);
       END_STATE();
-    case 51:
+    case 56:
       ACCEPT_TOKEN(sym_day_name);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(56);
       if (lookahead != 0 &&
           lookahead != '\n' &&
           lookahead != '(' &&
-          lookahead != ':') ADVANCE(51);
+          lookahead != ':') ADVANCE(55);
       END_STATE();
-    case 52:
+    case 57:
       ACCEPT_TOKEN(sym_count);
       END_STATE();
-    case 53:
+    case 58:
       ACCEPT_TOKEN(sym_rest_day);
       END_STATE();
-    case 54:
-      ACCEPT_TOKEN(anon_sym_COMMA);
-      END_STATE();
-    case 55:
+    case 59:
       ACCEPT_TOKEN(anon_sym_u27e8);
       END_STATE();
-    case 56:
+    case 60:
       ACCEPT_TOKEN(aux_sym_shopping_marker_token1);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(56);
       if (lookahead != 0 &&
-          lookahead != 0x27e9) ADVANCE(57);
+          lookahead != 0x27e9) ADVANCE(60);
       END_STATE();
-    case 57:
+    case 61:
       ACCEPT_TOKEN(aux_sym_shopping_marker_token1);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(61);
       if (lookahead != 0 &&
-          lookahead != 0x27e9) ADVANCE(57);
+          lookahead != 0x27e9) ADVANCE(60);
       END_STATE();
-    case 58:
+    case 62:
       ACCEPT_TOKEN(anon_sym_u27e9);
       END_STATE();
-    case 59:
+    case 63:
       ACCEPT_TOKEN(anon_sym_LBRACK_LBRACK);
       END_STATE();
-    case 60:
+    case 64:
       ACCEPT_TOKEN(aux_sym_dish_token1);
-      if (lookahead == '\t' ||
-          lookahead == ' ') ADVANCE(60);
       if (lookahead != 0 &&
-          lookahead != ']') ADVANCE(61);
+          lookahead != ']') ADVANCE(64);
       END_STATE();
-    case 61:
+    case 65:
       ACCEPT_TOKEN(aux_sym_dish_token1);
+      if (lookahead == '\t' ||
+          lookahead == ' ') ADVANCE(65);
       if (lookahead != 0 &&
-          lookahead != ']') ADVANCE(61);
+          lookahead != ']') ADVANCE(64);
       END_STATE();
-    case 62:
+    case 66:
       ACCEPT_TOKEN(anon_sym_RBRACK_RBRACK);
       END_STATE();
     default:
@@ -604,36 +700,47 @@ static const TSLexerMode ts_lex_modes[STATE_COUNT] = {
   [3] = {.lex_state = 0},
   [4] = {.lex_state = 0},
   [5] = {.lex_state = 0},
-  [6] = {.lex_state = 0},
+  [6] = {.lex_state = 1},
   [7] = {.lex_state = 0},
-  [8] = {.lex_state = 0},
+  [8] = {.lex_state = 1},
   [9] = {.lex_state = 0},
   [10] = {.lex_state = 0},
   [11] = {.lex_state = 0},
   [12] = {.lex_state = 0},
-  [13] = {.lex_state = 38},
-  [14] = {.lex_state = 38},
-  [15] = {.lex_state = 0},
-  [16] = {.lex_state = 38},
+  [13] = {.lex_state = 2},
+  [14] = {.lex_state = 2},
+  [15] = {.lex_state = 3},
+  [16] = {.lex_state = 0},
   [17] = {.lex_state = 0},
   [18] = {.lex_state = 0},
   [19] = {.lex_state = 0},
   [20] = {.lex_state = 0},
   [21] = {.lex_state = 0},
-  [22] = {.lex_state = 0},
+  [22] = {.lex_state = 2},
   [23] = {.lex_state = 0},
-  [24] = {.lex_state = 0},
+  [24] = {.lex_state = 3},
   [25] = {.lex_state = 0},
-  [26] = {.lex_state = 26},
-  [27] = {.lex_state = 27},
-  [28] = {.lex_state = 0},
+  [26] = {.lex_state = 0},
+  [27] = {.lex_state = 3},
+  [28] = {.lex_state = 1},
   [29] = {.lex_state = 0},
-  [30] = {.lex_state = 28},
-  [31] = {.lex_state = 29},
+  [30] = {.lex_state = 0},
+  [31] = {.lex_state = 0},
   [32] = {.lex_state = 0},
-  [33] = {.lex_state = 0},
+  [33] = {.lex_state = 1},
   [34] = {.lex_state = 0},
   [35] = {.lex_state = 0},
+  [36] = {.lex_state = 0},
+  [37] = {.lex_state = 3},
+  [38] = {.lex_state = 1},
+  [39] = {.lex_state = 0},
+  [40] = {.lex_state = 0},
+  [41] = {.lex_state = 0},
+  [42] = {.lex_state = 0},
+  [43] = {.lex_state = 4},
+  [44] = {.lex_state = 5},
+  [45] = {.lex_state = 0},
+  [46] = {.lex_state = 0},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
@@ -641,21 +748,23 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [ts_builtin_sym_end] = ACTIONS(1),
     [anon_sym_LF] = ACTIONS(1),
     [anon_sym_Personen_COLON] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_COMMA] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
     [anon_sym_Starttag_COLON] = ACTIONS(1),
     [sym_integer] = ACTIONS(1),
     [sym_date] = ACTIONS(1),
     [anon_sym_COLON] = ACTIONS(1),
     [sym_count] = ACTIONS(1),
     [sym_rest_day] = ACTIONS(1),
-    [anon_sym_COMMA] = ACTIONS(1),
     [anon_sym_u27e8] = ACTIONS(1),
     [anon_sym_u27e9] = ACTIONS(1),
     [anon_sym_LBRACK_LBRACK] = ACTIONS(1),
     [anon_sym_RBRACK_RBRACK] = ACTIONS(1),
   },
   [STATE(1)] = {
-    [sym_source_file] = STATE(35),
-    [sym_persons_line] = STATE(29),
+    [sym_source_file] = STATE(34),
+    [sym_persons_line] = STATE(35),
     [anon_sym_Personen_COLON] = ACTIONS(3),
   },
 };
@@ -668,18 +777,18 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_u27e8,
     ACTIONS(11), 1,
       anon_sym_LBRACK_LBRACK,
-    STATE(4), 1,
+    STATE(3), 1,
       sym_menu_item,
-    STATE(8), 1,
+    STATE(6), 1,
       sym_dish,
-    STATE(19), 1,
-      sym_menu,
-    STATE(20), 1,
+    STATE(31), 1,
       sym_menu_items,
+    STATE(32), 1,
+      sym_menu,
     ACTIONS(5), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-    STATE(11), 2,
+    STATE(9), 2,
       sym_shopping_marker,
       sym_dish_with_count,
   [30] = 7,
@@ -689,15 +798,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK_LBRACK,
     ACTIONS(15), 1,
       anon_sym_COMMA,
-    STATE(8), 1,
+    STATE(6), 1,
       sym_dish,
     ACTIONS(13), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-    STATE(5), 2,
+    STATE(4), 2,
       sym_menu_item,
       aux_sym_menu_items_repeat1,
-    STATE(11), 2,
+    STATE(9), 2,
       sym_shopping_marker,
       sym_dish_with_count,
   [55] = 7,
@@ -707,15 +816,15 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACK_LBRACK,
     ACTIONS(15), 1,
       anon_sym_COMMA,
-    STATE(8), 1,
+    STATE(6), 1,
       sym_dish,
     ACTIONS(17), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-    STATE(3), 2,
+    STATE(5), 2,
       sym_menu_item,
       aux_sym_menu_items_repeat1,
-    STATE(11), 2,
+    STATE(9), 2,
       sym_shopping_marker,
       sym_dish_with_count,
   [80] = 7,
@@ -725,7 +834,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_u27e8,
     ACTIONS(27), 1,
       anon_sym_LBRACK_LBRACK,
-    STATE(8), 1,
+    STATE(6), 1,
       sym_dish,
     ACTIONS(19), 2,
       ts_builtin_sym_end,
@@ -733,36 +842,36 @@ static const uint16_t ts_small_parse_table[] = {
     STATE(5), 2,
       sym_menu_item,
       aux_sym_menu_items_repeat1,
-    STATE(11), 2,
+    STATE(9), 2,
       sym_shopping_marker,
       sym_dish_with_count,
-  [105] = 5,
+  [105] = 2,
+    ACTIONS(32), 1,
+      sym_count,
+    ACTIONS(30), 5,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+      anon_sym_COMMA,
+      anon_sym_u27e8,
+      anon_sym_LBRACK_LBRACK,
+  [116] = 5,
     ACTIONS(9), 1,
       anon_sym_u27e8,
     ACTIONS(11), 1,
       anon_sym_LBRACK_LBRACK,
-    STATE(8), 1,
+    STATE(6), 1,
       sym_dish,
     STATE(12), 1,
       sym_menu_item,
-    STATE(11), 2,
+    STATE(9), 2,
       sym_shopping_marker,
       sym_dish_with_count,
-  [122] = 1,
-    ACTIONS(30), 6,
+  [133] = 1,
+    ACTIONS(34), 6,
       ts_builtin_sym_end,
       anon_sym_LF,
-      sym_count,
       anon_sym_COMMA,
-      anon_sym_u27e8,
-      anon_sym_LBRACK_LBRACK,
-  [131] = 2,
-    ACTIONS(34), 1,
       sym_count,
-    ACTIONS(32), 5,
-      ts_builtin_sym_end,
-      anon_sym_LF,
-      anon_sym_COMMA,
       anon_sym_u27e8,
       anon_sym_LBRACK_LBRACK,
   [142] = 1,
@@ -798,106 +907,161 @@ static const uint16_t ts_small_parse_table[] = {
       ts_builtin_sym_end,
     ACTIONS(44), 1,
       sym_day_name,
-    STATE(22), 1,
+    STATE(29), 1,
       sym_day_line,
-    STATE(34), 1,
+    STATE(40), 1,
       sym_day_with_count,
   [187] = 4,
     ACTIONS(44), 1,
       sym_day_name,
     ACTIONS(46), 1,
       ts_builtin_sym_end,
-    STATE(22), 1,
+    STATE(29), 1,
       sym_day_line,
-    STATE(34), 1,
+    STATE(40), 1,
       sym_day_with_count,
   [200] = 3,
     ACTIONS(48), 1,
-      ts_builtin_sym_end,
-    ACTIONS(50), 1,
       anon_sym_LF,
-    STATE(15), 1,
-      aux_sym_source_file_repeat1,
+    ACTIONS(50), 1,
+      anon_sym_LPAREN,
+    STATE(36), 1,
+      sym_groups,
   [210] = 3,
-    ACTIONS(44), 1,
-      sym_day_name,
-    STATE(22), 1,
-      sym_day_line,
-    STATE(34), 1,
-      sym_day_with_count,
-  [220] = 3,
-    ACTIONS(53), 1,
+    ACTIONS(52), 1,
       ts_builtin_sym_end,
-    ACTIONS(55), 1,
+    ACTIONS(54), 1,
       anon_sym_LF,
     STATE(18), 1,
       aux_sym_source_file_repeat1,
+  [220] = 3,
+    ACTIONS(56), 1,
+      anon_sym_COMMA,
+    ACTIONS(58), 1,
+      anon_sym_RPAREN,
+    STATE(19), 1,
+      aux_sym_groups_repeat1,
   [230] = 3,
-    ACTIONS(46), 1,
+    ACTIONS(42), 1,
       ts_builtin_sym_end,
-    ACTIONS(57), 1,
+    ACTIONS(60), 1,
       anon_sym_LF,
-    STATE(15), 1,
+    STATE(20), 1,
       aux_sym_source_file_repeat1,
-  [240] = 1,
-    ACTIONS(59), 2,
-      ts_builtin_sym_end,
-      anon_sym_LF,
-  [245] = 1,
-    ACTIONS(61), 2,
+  [240] = 3,
+    ACTIONS(56), 1,
+      anon_sym_COMMA,
+    ACTIONS(62), 1,
+      anon_sym_RPAREN,
+    STATE(21), 1,
+      aux_sym_groups_repeat1,
+  [250] = 3,
+    ACTIONS(64), 1,
       ts_builtin_sym_end,
+    ACTIONS(66), 1,
       anon_sym_LF,
-  [250] = 2,
-    ACTIONS(63), 1,
+    STATE(20), 1,
+      aux_sym_source_file_repeat1,
+  [260] = 3,
+    ACTIONS(69), 1,
+      anon_sym_COMMA,
+    ACTIONS(72), 1,
+      anon_sym_RPAREN,
+    STATE(21), 1,
+      aux_sym_groups_repeat1,
+  [270] = 3,
+    ACTIONS(44), 1,
+      sym_day_name,
+    STATE(29), 1,
+      sym_day_line,
+    STATE(40), 1,
+      sym_day_with_count,
+  [280] = 2,
+    ACTIONS(74), 1,
       anon_sym_Starttag_COLON,
-    STATE(17), 1,
+    STATE(16), 1,
       sym_starttag_line,
-  [257] = 1,
-    ACTIONS(48), 2,
-      ts_builtin_sym_end,
-      anon_sym_LF,
-  [262] = 1,
-    ACTIONS(65), 2,
+  [287] = 2,
+    ACTIONS(76), 1,
+      sym_diet,
+    STATE(17), 1,
+      sym_group,
+  [294] = 1,
+    ACTIONS(78), 2,
       ts_builtin_sym_end,
       anon_sym_LF,
-  [267] = 2,
-    ACTIONS(67), 1,
+  [299] = 1,
+    ACTIONS(80), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [304] = 2,
+    ACTIONS(76), 1,
+      sym_diet,
+    STATE(30), 1,
+      sym_group,
+  [311] = 2,
+    ACTIONS(82), 1,
       anon_sym_COLON,
-    ACTIONS(69), 1,
+    ACTIONS(84), 1,
       sym_count,
-  [274] = 1,
-    ACTIONS(71), 1,
+  [318] = 1,
+    ACTIONS(64), 2,
+      ts_builtin_sym_end,
       anon_sym_LF,
-  [278] = 1,
-    ACTIONS(73), 1,
-      aux_sym_shopping_marker_token1,
-  [282] = 1,
-    ACTIONS(75), 1,
-      aux_sym_dish_token1,
-  [286] = 1,
-    ACTIONS(77), 1,
-      anon_sym_COLON,
-  [290] = 1,
-    ACTIONS(79), 1,
+  [323] = 1,
+    ACTIONS(72), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [328] = 1,
+    ACTIONS(86), 2,
+      ts_builtin_sym_end,
       anon_sym_LF,
-  [294] = 1,
-    ACTIONS(81), 1,
+  [333] = 1,
+    ACTIONS(88), 2,
+      ts_builtin_sym_end,
+      anon_sym_LF,
+  [338] = 1,
+    ACTIONS(90), 1,
+      sym_integer,
+  [342] = 1,
+    ACTIONS(92), 1,
+      ts_builtin_sym_end,
+  [346] = 1,
+    ACTIONS(94), 1,
+      anon_sym_LF,
+  [350] = 1,
+    ACTIONS(96), 1,
+      anon_sym_LF,
+  [354] = 1,
+    ACTIONS(98), 1,
       sym_date,
-  [298] = 1,
-    ACTIONS(83), 1,
+  [358] = 1,
+    ACTIONS(100), 1,
       sym_integer,
-  [302] = 1,
-    ACTIONS(85), 1,
-      anon_sym_RBRACK_RBRACK,
-  [306] = 1,
-    ACTIONS(87), 1,
-      anon_sym_u27e9,
-  [310] = 1,
-    ACTIONS(89), 1,
+  [362] = 1,
+    ACTIONS(102), 1,
+      anon_sym_LF,
+  [366] = 1,
+    ACTIONS(104), 1,
       anon_sym_COLON,
-  [314] = 1,
-    ACTIONS(91), 1,
-      ts_builtin_sym_end,
+  [370] = 1,
+    ACTIONS(106), 1,
+      anon_sym_LF,
+  [374] = 1,
+    ACTIONS(108), 1,
+      anon_sym_COLON,
+  [378] = 1,
+    ACTIONS(110), 1,
+      aux_sym_shopping_marker_token1,
+  [382] = 1,
+    ACTIONS(112), 1,
+      aux_sym_dish_token1,
+  [386] = 1,
+    ACTIONS(114), 1,
+      anon_sym_u27e9,
+  [390] = 1,
+    ACTIONS(116), 1,
+      anon_sym_RBRACK_RBRACK,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
@@ -906,8 +1070,8 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(4)] = 55,
   [SMALL_STATE(5)] = 80,
   [SMALL_STATE(6)] = 105,
-  [SMALL_STATE(7)] = 122,
-  [SMALL_STATE(8)] = 131,
+  [SMALL_STATE(7)] = 116,
+  [SMALL_STATE(8)] = 133,
   [SMALL_STATE(9)] = 142,
   [SMALL_STATE(10)] = 150,
   [SMALL_STATE(11)] = 158,
@@ -919,70 +1083,93 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(17)] = 220,
   [SMALL_STATE(18)] = 230,
   [SMALL_STATE(19)] = 240,
-  [SMALL_STATE(20)] = 245,
-  [SMALL_STATE(21)] = 250,
-  [SMALL_STATE(22)] = 257,
-  [SMALL_STATE(23)] = 262,
-  [SMALL_STATE(24)] = 267,
-  [SMALL_STATE(25)] = 274,
-  [SMALL_STATE(26)] = 278,
-  [SMALL_STATE(27)] = 282,
-  [SMALL_STATE(28)] = 286,
-  [SMALL_STATE(29)] = 290,
-  [SMALL_STATE(30)] = 294,
-  [SMALL_STATE(31)] = 298,
-  [SMALL_STATE(32)] = 302,
-  [SMALL_STATE(33)] = 306,
-  [SMALL_STATE(34)] = 310,
-  [SMALL_STATE(35)] = 314,
+  [SMALL_STATE(20)] = 250,
+  [SMALL_STATE(21)] = 260,
+  [SMALL_STATE(22)] = 270,
+  [SMALL_STATE(23)] = 280,
+  [SMALL_STATE(24)] = 287,
+  [SMALL_STATE(25)] = 294,
+  [SMALL_STATE(26)] = 299,
+  [SMALL_STATE(27)] = 304,
+  [SMALL_STATE(28)] = 311,
+  [SMALL_STATE(29)] = 318,
+  [SMALL_STATE(30)] = 323,
+  [SMALL_STATE(31)] = 328,
+  [SMALL_STATE(32)] = 333,
+  [SMALL_STATE(33)] = 338,
+  [SMALL_STATE(34)] = 342,
+  [SMALL_STATE(35)] = 346,
+  [SMALL_STATE(36)] = 350,
+  [SMALL_STATE(37)] = 354,
+  [SMALL_STATE(38)] = 358,
+  [SMALL_STATE(39)] = 362,
+  [SMALL_STATE(40)] = 366,
+  [SMALL_STATE(41)] = 370,
+  [SMALL_STATE(42)] = 374,
+  [SMALL_STATE(43)] = 378,
+  [SMALL_STATE(44)] = 382,
+  [SMALL_STATE(45)] = 386,
+  [SMALL_STATE(46)] = 390,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_line, 2, 0, 5),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
-  [13] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu_items, 2, 0, 0),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
-  [17] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu_items, 1, 0, 0),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_line, 2, 0, 7),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(44),
+  [13] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu_items, 1, 0, 0),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [17] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu_items, 2, 0, 0),
   [19] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0),
-  [21] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0), SHIFT_REPEAT(6),
-  [24] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0), SHIFT_REPEAT(26),
-  [27] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0), SHIFT_REPEAT(27),
-  [30] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dish, 3, 0, 8),
-  [32] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dish_with_count, 1, 0, 6),
-  [34] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [36] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dish_with_count, 2, 0, 7),
-  [38] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shopping_marker, 3, 0, 0),
-  [40] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu_item, 1, 0, 0),
-  [42] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 5, 0, 0),
-  [44] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [46] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 0),
-  [48] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
-  [50] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(16),
-  [53] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_line, 3, 0, 5),
-  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu, 1, 0, 0),
-  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_starttag_line, 2, 0, 2),
-  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_with_count, 1, 0, 3),
-  [69] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_persons_line, 2, 0, 1),
-  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
-  [77] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_with_count, 2, 0, 4),
-  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
-  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
-  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [91] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [21] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0), SHIFT_REPEAT(7),
+  [24] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0), SHIFT_REPEAT(43),
+  [27] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_menu_items_repeat1, 2, 0, 0), SHIFT_REPEAT(44),
+  [30] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dish_with_count, 1, 0, 8),
+  [32] = {.entry = {.count = 1, .reusable = true}}, SHIFT(10),
+  [34] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dish, 3, 0, 10),
+  [36] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu_item, 1, 0, 0),
+  [38] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_dish_with_count, 2, 0, 9),
+  [40] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_shopping_marker, 3, 0, 0),
+  [42] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 4, 0, 0),
+  [44] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
+  [46] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 5, 0, 0),
+  [48] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_persons_line, 2, 0, 1),
+  [50] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [52] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3, 0, 0),
+  [54] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [56] = {.entry = {.count = 1, .reusable = true}}, SHIFT(27),
+  [58] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [60] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
+  [62] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [64] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0),
+  [66] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2, 0, 0), SHIFT_REPEAT(22),
+  [69] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_groups_repeat1, 2, 0, 0), SHIFT_REPEAT(27),
+  [72] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_groups_repeat1, 2, 0, 0),
+  [74] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [76] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [78] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_starttag_line, 2, 0, 3),
+  [80] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 2, 0, 4),
+  [82] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_with_count, 1, 0, 5),
+  [84] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
+  [86] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_menu, 1, 0, 0),
+  [88] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_line, 3, 0, 7),
+  [90] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [92] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [94] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
+  [96] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_persons_line, 3, 0, 2),
+  [98] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
+  [100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(26),
+  [102] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_groups, 3, 0, 0),
+  [104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [106] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_groups, 4, 0, 0),
+  [108] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_day_with_count, 2, 0, 6),
+  [110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
+  [114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
 };
 
 #ifdef __cplusplus